@@ -9,18 +9,23 @@ use crate::{
     QuestionSubmissionList, SubmissionListResponse,
     error::{LeetCodeErr, Result},
     models::{
-        DailyChallenge, DailyChallengeOuter, GlobalData, GqlResponse, MatchedUser,
+        DailyChallenge, DailyChallengeOuter, Difficulty, GlobalData, GqlResponse, MatchedUser,
         ProblemsetQuestionList, Question, QuestionListOuter, QuestionOuter,
         SubmissionCheckResponse, SubmissionResponse, TestCasesCheckResponse, TestCasesResponse,
         UserProfile, UserStatus,
     },
 };
 
-const BASE_URL: &str = "https://leetcode.com";
+pub(crate) const BASE_URL: &str = "https://leetcode.com";
+
+const BROWSER_USER_AGENT: &str = "Mozilla/5.0 (Macintosh; Intel Mac OS X 10_15_7) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/121.0.0.0 Safari/537.36";
 
 /// The way to communicate with the LeetCode api.
 pub struct LeetCodeClient {
     client: Client,
+    /// Downloads description images, which may be hosted anywhere, so it
+    /// must not carry the user's credentials.
+    images: Client,
     session: String,
     csrf: String,
 }
@@ -37,12 +42,14 @@ impl LeetCodeClient {
         headers.insert("x-csrftoken", HeaderValue::from_str(&csrf)?);
         headers.insert(CONTENT_TYPE, HeaderValue::from_static("application/json"));
         headers.insert("Referer", HeaderValue::from_static("https://leetcode.com"));
-        headers.insert(USER_AGENT, HeaderValue::from_static("Mozilla/5.0 (Macintosh; Intel Mac OS X 10_15_7) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/121.0.0.0 Safari/537.36"));
+        headers.insert(USER_AGENT, HeaderValue::from_static(BROWSER_USER_AGENT));
 
         let client = Client::builder().default_headers(headers).build()?;
+        let images = Client::builder().user_agent(BROWSER_USER_AGENT).build()?;
 
         Ok(Self {
             client,
+            images,
             session,
             csrf,
         })
@@ -54,7 +61,7 @@ impl LeetCodeClient {
     /// The status for this specific user.
     pub async fn get_status(&self) -> Result<UserStatus> {
         let query = include_str!("../queries/get_status.graphql");
-        let data: GlobalData = self.request_graphql(query, {}).await?;
+        let data: GlobalData = self.request_graphql(query, json!({})).await?;
         Ok(data.user_status)
     }
 
@@ -82,7 +89,7 @@ impl LeetCodeClient {
     /// The daily problem.
     pub async fn get_daily_challenge(&self) -> Result<DailyChallenge> {
         let query = include_str!("../queries/get_daily_challenge.graphql");
-        let data: DailyChallengeOuter = self.request_graphql(query, {}).await?;
+        let data: DailyChallengeOuter = self.request_graphql(query, json!({})).await?;
         Ok(data.active_daily_coding_challenge_question)
     }
 
@@ -111,6 +118,7 @@ impl LeetCodeClient {
     /// # Arguments
     /// * `skip` - The offset to start the list out of.
     /// * `limit` - The maximum amount of questions to retrieve at once.
+    /// * `difficulty` - The only difficulty to list, if any.
     ///
     /// # Returns
     /// A list of questions.
@@ -118,13 +126,19 @@ impl LeetCodeClient {
         &self,
         skip: usize,
         limit: usize,
+        difficulty: Option<Difficulty>,
     ) -> Result<ProblemsetQuestionList> {
         let query = include_str!("../queries/get_problem_list.graphql");
+        let mut filters = json!({});
+        if let Some(difficulty) = difficulty {
+            filters["difficulty"] = json!(difficulty.filter_value());
+        }
+
         let vars = json!({
             "categorySlug": "",
             "skip": skip,
             "limit": limit,
-            "filters": {},
+            "filters": filters,
         });
 
         let data: QuestionListOuter = self.request_graphql(query, vars).await?;
@@ -137,6 +151,7 @@ impl LeetCodeClient {
     /// * `skip` - The offset to start the list out of.
     /// * `limit` - The maximum amount of questions to retrieve at once.
     /// * `keywords` - A string of keywords to search by.
+    /// * `difficulty` - The only difficulty to list, if any.
     ///
     /// # Returns
     /// A list of questions.
@@ -145,15 +160,19 @@ impl LeetCodeClient {
         skip: usize,
         limit: usize,
         keywords: &str,
+        difficulty: Option<Difficulty>,
     ) -> Result<ProblemsetQuestionList> {
         let query = include_str!("../queries/get_problem_list.graphql");
+        let mut filters = json!({ "searchKeywords": keywords });
+        if let Some(difficulty) = difficulty {
+            filters["difficulty"] = json!(difficulty.filter_value());
+        }
+
         let vars = json!({
             "categorySlug": "",
             "skip": skip,
             "limit": limit,
-            "filters": {
-                "searchKeywords": keywords,
-            },
+            "filters": filters,
         });
 
         let data: QuestionListOuter = self.request_graphql(query, vars).await?;
@@ -272,6 +291,24 @@ impl LeetCodeClient {
         self.raw_request(Method::GET, &url, Value::Null).await
     }
 
+    /// Downloads an image referenced by a problem description.
+    ///
+    /// # Arguments
+    /// * `url` - The url of the image.
+    ///
+    /// # Returns
+    /// The raw bytes of the image.
+    pub async fn get_image(&self, url: &str) -> Result<Vec<u8>> {
+        let res = self.images.get(url).send().await?;
+        let status = res.status();
+
+        if !status.is_success() {
+            return Err(LeetCodeErr::Api(format!("Status: {status}\nImage: {url}")));
+        }
+
+        Ok(res.bytes().await?.to_vec())
+    }
+
     /// Maes a GraphQL request to the `/graphql` endpoint.
     ///
     /// # Arguments
@@ -339,6 +376,7 @@ impl LeetCodeClient {
             client,
             session,
             csrf,
+            ..
         } = self;
 
         let cookie = format!("LEETCODE_SESSION={session}; csrftoken={csrf}");
@@ -0,0 +1,455 @@
+use super::{Block, Document, Inline, scripts::marked};
+
+/// The range a variable is constrained to, like `1 <= n <= 10^5`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Bound {
+    /// The constrained variable, like `n` or `nums[i]`.
+    pub variable: String,
+    pub lower: Option<Limit>,
+    pub upper: Option<Limit>,
+}
+
+/// One end of a range.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Limit {
+    /// The limit as written, using `^` for powers, like `2^31 - 1`.
+    pub expr: String,
+    /// Whether the limit itself is part of the range.
+    pub inclusive: bool,
+}
+
+impl Limit {
+    /// Evaluates the limit, if it's a plain arithmetic expression.
+    pub fn value(&self) -> Option<i64> {
+        let tokens = tokenize(&self.expr)?;
+        let mut parser = Evaluator { tokens, pos: 0 };
+        let value = parser.sum()?;
+
+        if parser.pos != parser.tokens.len() {
+            return None;
+        }
+
+        i64::try_from(value).ok()
+    }
+}
+
+impl Bound {
+    /// Returns the smallest value allowed, taking strict limits into account.
+    pub fn min(&self) -> Option<i64> {
+        let lower = self.lower.as_ref()?;
+        let value = lower.value()?;
+
+        match lower.inclusive {
+            true => Some(value),
+            false => value.checked_add(1),
+        }
+    }
+
+    /// Returns the largest value allowed, taking strict limits into account.
+    pub fn max(&self) -> Option<i64> {
+        let upper = self.upper.as_ref()?;
+        let value = upper.value()?;
+
+        match upper.inclusive {
+            true => Some(value),
+            false => value.checked_sub(1),
+        }
+    }
+}
+
+impl Document {
+    /// Reads the bounds stated in the Constraints section.
+    ///
+    /// Constraints that aren't ranges, like `s consists of lowercase letters`,
+    /// are skipped, and constraints over several variables, like
+    /// `1 <= m, n <= 200`, give a bound for each.
+    pub fn bounds(&self) -> Vec<Bound> {
+        self.blocks
+            .iter()
+            .filter_map(|block| match block {
+                Block::Constraints(items) => Some(items),
+                _ => None,
+            })
+            .flatten()
+            .flat_map(|item| parse_bounds(&math_text(item)))
+            .collect()
+    }
+}
+
+/// Writes inline elements as text, using `^` and `_` for superscripts and subscripts.
+fn math_text(inlines: &[Inline]) -> String {
+    inlines
+        .iter()
+        .map(|inline| match inline {
+            Inline::Text(text) => text.clone(),
+            Inline::Strong(content)
+            | Inline::Emphasis(content)
+            | Inline::Code(content)
+            | Inline::Link { content, .. } => math_text(content),
+            Inline::Superscript(content) => marked(&math_text(content), '^'),
+            Inline::Subscript(content) => marked(&math_text(content), '_'),
+            Inline::Image(_) => String::new(),
+            Inline::LineBreak => " ".to_string(),
+        })
+        .collect()
+}
+
+/// A comparison between the parts of a constraint.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Comparison {
+    Less { inclusive: bool },
+    Greater { inclusive: bool },
+}
+
+/// Parses the bounds of a single constraint.
+fn parse_bounds(text: &str) -> Vec<Bound> {
+    let text = text
+        .trim()
+        .trim_end_matches('.')
+        .replace('≤', "<=")
+        .replace('≥', ">=")
+        .replace('−', "-");
+
+    if let Some(bounds) = parse_range(&text) {
+        return bounds;
+    }
+
+    let (parts, comparisons) = split_comparisons(&text);
+    let limit = |expr: &str, inclusive| Limit {
+        expr: expr.trim().to_string(),
+        inclusive,
+    };
+
+    let (variables, lower, upper) = match (parts.as_slice(), comparisons.as_slice()) {
+        (
+            [lower, vars, upper],
+            [
+                Comparison::Less { inclusive: l },
+                Comparison::Less { inclusive: u },
+            ],
+        ) => (*vars, Some(limit(lower, *l)), Some(limit(upper, *u))),
+        (
+            [upper, vars, lower],
+            [
+                Comparison::Greater { inclusive: u },
+                Comparison::Greater { inclusive: l },
+            ],
+        ) => (*vars, Some(limit(lower, *l)), Some(limit(upper, *u))),
+        ([left, right], [comparison]) => {
+            let left_is_limit = limit(left, true).value().is_some();
+            let right_is_limit = limit(right, true).value().is_some();
+
+            match (comparison, left_is_limit, right_is_limit) {
+                (Comparison::Less { inclusive }, false, true) => {
+                    (*left, None, Some(limit(right, *inclusive)))
+                }
+                (Comparison::Less { inclusive }, true, false) => {
+                    (*right, Some(limit(left, *inclusive)), None)
+                }
+                (Comparison::Greater { inclusive }, false, true) => {
+                    (*left, Some(limit(right, *inclusive)), None)
+                }
+                (Comparison::Greater { inclusive }, true, false) => {
+                    (*right, None, Some(limit(left, *inclusive)))
+                }
+                _ => return Vec::new(),
+            }
+        }
+        _ => return Vec::new(),
+    };
+
+    variables
+        .split([',', '='])
+        .map(str::trim)
+        .filter(|variable| !variable.is_empty())
+        .map(|variable| Bound {
+            variable: variable.to_string(),
+            lower: lower.clone(),
+            upper: upper.clone(),
+        })
+        .collect()
+}
+
+/// Parses constraints like `The number of nodes in the tree is in the range [1, 10^4]`.
+fn parse_range(text: &str) -> Option<Vec<Bound>> {
+    let (variable, range) = text.split_once(" in the range ")?;
+    let (lower, upper) = range
+        .trim()
+        .strip_prefix('[')?
+        .strip_suffix(']')?
+        .split_once(',')?;
+
+    let variable = variable.trim().trim_end_matches(" is").trim();
+    let variable = variable.strip_prefix("The ").unwrap_or(variable);
+
+    Some(vec![Bound {
+        variable: variable.to_string(),
+        lower: Some(Limit {
+            expr: lower.trim().to_string(),
+            inclusive: true,
+        }),
+        upper: Some(Limit {
+            expr: upper.trim().to_string(),
+            inclusive: true,
+        }),
+    }])
+}
+
+/// Splits a constraint at its `<`, `<=`, `>` and `>=` signs.
+fn split_comparisons(text: &str) -> (Vec<&str>, Vec<Comparison>) {
+    let mut parts = Vec::new();
+    let mut comparisons = Vec::new();
+    let mut start = 0;
+    let mut chars = text.char_indices().peekable();
+
+    while let Some((i, ch)) = chars.next() {
+        let less = match ch {
+            '<' => true,
+            '>' => false,
+            _ => continue,
+        };
+
+        let inclusive = chars.next_if(|(_, next)| *next == '=').is_some();
+        let end = if inclusive { i + 2 } else { i + 1 };
+
+        parts.push(&text[start..i]);
+        comparisons.push(match less {
+            true => Comparison::Less { inclusive },
+            false => Comparison::Greater { inclusive },
+        });
+        start = end;
+    }
+
+    parts.push(&text[start..]);
+    (parts, comparisons)
+}
+
+/// A token of an arithmetic expression.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Token {
+    Number(i128),
+    Op(char),
+}
+
+fn tokenize(expr: &str) -> Option<Vec<Token>> {
+    let mut tokens = Vec::new();
+    let mut chars = expr.chars().peekable();
+
+    while let Some(ch) = chars.next() {
+        match ch {
+            _ if ch.is_whitespace() => {}
+            '0'..='9' => {
+                let mut number = i128::from(ch.to_digit(10)?);
+                while let Some(digit) = chars.peek().and_then(|ch| ch.to_digit(10)) {
+                    number = number.checked_mul(10)?.checked_add(i128::from(digit))?;
+                    chars.next();
+                }
+
+                tokens.push(Token::Number(number));
+            }
+            '+' | '-' | '*' | '×' | '^' | '(' | ')' => tokens.push(Token::Op(ch)),
+            _ => return None,
+        }
+    }
+
+    Some(tokens)
+}
+
+/// Evaluates arithmetic expressions with `+`, `-`, `*`, `^` and parentheses.
+struct Evaluator {
+    tokens: Vec<Token>,
+    pos: usize,
+}
+
+impl Evaluator {
+    fn peek_op(&self) -> Option<char> {
+        match self.tokens.get(self.pos) {
+            Some(Token::Op(op)) => Some(*op),
+            _ => None,
+        }
+    }
+
+    fn sum(&mut self) -> Option<i128> {
+        let mut value = self.product()?;
+
+        while let Some(op @ ('+' | '-')) = self.peek_op() {
+            self.pos += 1;
+            let rhs = self.product()?;
+            value = match op {
+                '+' => value.checked_add(rhs)?,
+                _ => value.checked_sub(rhs)?,
+            };
+        }
+
+        Some(value)
+    }
+
+    fn product(&mut self) -> Option<i128> {
+        let mut value = self.unary()?;
+
+        while let Some('*' | '×') = self.peek_op() {
+            self.pos += 1;
+            value = value.checked_mul(self.unary()?)?;
+        }
+
+        Some(value)
+    }
+
+    fn unary(&mut self) -> Option<i128> {
+        if self.peek_op() == Some('-') {
+            self.pos += 1;
+            return self.unary()?.checked_neg();
+        }
+
+        self.power()
+    }
+
+    fn power(&mut self) -> Option<i128> {
+        let base = self.atom()?;
+
+        if self.peek_op() != Some('^') {
+            return Some(base);
+        }
+
+        self.pos += 1;
+        let exp = u32::try_from(self.unary()?).ok()?;
+        base.checked_pow(exp)
+    }
+
+    fn atom(&mut self) -> Option<i128> {
+        match *self.tokens.get(self.pos)? {
+            Token::Op('(') => {
+                self.pos += 1;
+                let value = self.sum()?;
+                if self.peek_op() != Some(')') {
+                    return None;
+                }
+
+                self.pos += 1;
+                Some(value)
+            }
+            Token::Number(n) => {
+                self.pos += 1;
+                Some(n)
+            }
+            Token::Op(_) => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Parses a constraint that should give a single bound.
+    fn single_bound(text: &str) -> Bound {
+        let mut bounds = parse_bounds(text);
+        assert_eq!(bounds.len(), 1, "{text}");
+        bounds.remove(0)
+    }
+
+    #[test]
+    fn parses_inclusive_chain() {
+        let bound = single_bound("1 <= nums.length <= 100");
+        assert_eq!(bound.variable, "nums.length");
+        assert_eq!(bound.min(), Some(1));
+        assert_eq!(bound.max(), Some(100));
+    }
+
+    #[test]
+    fn parses_strict_chain() {
+        let bound = single_bound("0 < k < 10");
+        assert_eq!(bound.min(), Some(1));
+        assert_eq!(bound.max(), Some(9));
+    }
+
+    #[test]
+    fn parses_mixed_and_descending_chains() {
+        let bound = single_bound("0 <= i < n");
+        assert_eq!(bound.variable, "i");
+        assert_eq!(bound.min(), Some(0));
+        assert_eq!(bound.upper.as_ref().map(|upper| upper.value()), Some(None));
+
+        let bound = single_bound("10 >= x > 2");
+        assert_eq!(bound.min(), Some(3));
+        assert_eq!(bound.max(), Some(10));
+    }
+
+    #[test]
+    fn parses_one_sided_comparisons() {
+        let bound = single_bound("n >= 1");
+        assert_eq!(bound.min(), Some(1));
+        assert_eq!(bound.upper, None);
+
+        let bound = single_bound("10^4 > k");
+        assert_eq!(bound.variable, "k");
+        assert_eq!(bound.min(), None);
+        assert_eq!(bound.max(), Some(9_999));
+    }
+
+    #[test]
+    fn evaluates_exponents() {
+        let bound = single_bound("-2^31 <= nums[i] <= 2^31 - 1");
+        assert_eq!(bound.min(), Some(-2_147_483_648));
+        assert_eq!(bound.max(), Some(2_147_483_647));
+
+        let bound = single_bound("1 <= n <= 5 * 10^4");
+        assert_eq!(bound.max(), Some(50_000));
+    }
+
+    #[test]
+    fn normalizes_unicode_signs() {
+        let bound = single_bound("−10 ≤ x ≤ 10.");
+        assert_eq!(bound.min(), Some(-10));
+        assert_eq!(bound.max(), Some(10));
+    }
+
+    #[test]
+    fn gives_a_bound_per_variable() {
+        let bounds = parse_bounds("1 <= m, n <= 200");
+        let variables: Vec<_> = bounds.iter().map(|b| b.variable.as_str()).collect();
+        assert_eq!(variables, ["m", "n"]);
+        assert!(bounds.iter().all(|b| b.max() == Some(200)));
+    }
+
+    #[test]
+    fn parses_ranges() {
+        let bound = single_bound("The number of nodes in the tree is in the range [0, 10^4].");
+        assert_eq!(bound.variable, "number of nodes in the tree");
+        assert_eq!(bound.min(), Some(0));
+        assert_eq!(bound.max(), Some(10_000));
+    }
+
+    #[test]
+    fn skips_unparseable_constraints() {
+        assert!(parse_bounds("s consists of lowercase English letters.").is_empty());
+        assert!(parse_bounds("a <= b").is_empty());
+        assert!(parse_bounds("1 <= 2").is_empty());
+        assert!(parse_bounds("").is_empty());
+    }
+
+    #[test]
+    fn rejects_limits_that_dont_evaluate() {
+        for expr in ["10^", "(1 + 2", "1 + + 2", "2^-1", "10^100", "n"] {
+            let limit = Limit {
+                expr: expr.to_string(),
+                inclusive: true,
+            };
+            assert_eq!(limit.value(), None, "{expr}");
+        }
+    }
+
+    #[test]
+    fn reads_bounds_from_a_description() {
+        let document = Document::parse(
+            "<p><strong>Constraints:</strong></p>\
+             <ul><li><code>1 &lt;= nums.length &lt;= 10<sup>4</sup></code></li>\
+             <li><code>nums</code> is sorted.</li></ul>",
+        );
+
+        let bounds = document.bounds();
+        assert_eq!(bounds.len(), 1);
+        assert_eq!(bounds[0].variable, "nums.length");
+        assert_eq!(bounds[0].max(), Some(10_000));
+    }
+}
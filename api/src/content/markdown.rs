@@ -0,0 +1,143 @@
+use super::{Block, Example, Image, Inline, indent, inline_text};
+
+/// Renders blocks as Markdown, separated by blank lines.
+pub(super) fn blocks_to_markdown(blocks: &[Block]) -> String {
+    blocks
+        .iter()
+        .map(block_to_markdown)
+        .collect::<Vec<_>>()
+        .join("\n\n")
+}
+
+fn block_to_markdown(block: &Block) -> String {
+    match block {
+        Block::Paragraph(inlines) => inlines_to_markdown(inlines),
+        Block::Heading(inlines) => format!("### {}", inlines_to_markdown(inlines)),
+        Block::Example(example) => example_to_markdown(example),
+        Block::Constraints(items) => {
+            let items: Vec<_> = items
+                .iter()
+                .map(|item| format!("- {}", indent(&inlines_to_markdown(item), "  ")))
+                .collect();
+
+            format!("**Constraints:**\n\n{}", items.join("\n"))
+        }
+        Block::Code(code) => fence(code),
+        Block::Image(image) => image_to_markdown(image),
+        Block::List { ordered, items } => items
+            .iter()
+            .enumerate()
+            .map(|(i, item)| {
+                let marker = match ordered {
+                    true => format!("{}. ", i + 1),
+                    false => "- ".to_string(),
+                };
+
+                let pad = " ".repeat(marker.len());
+                format!("{marker}{}", indent(&blocks_to_markdown(item), &pad))
+            })
+            .collect::<Vec<_>>()
+            .join("\n"),
+        Block::Quote(blocks) => blocks_to_markdown(blocks)
+            .lines()
+            .map(|line| format!("> {line}").trim_end().to_string())
+            .collect::<Vec<_>>()
+            .join("\n"),
+        Block::Rule => "---".to_string(),
+    }
+}
+
+fn example_to_markdown(example: &Example) -> String {
+    let mut parts = vec![format!("**{}:**", example.title)];
+    parts.extend(example.images.iter().map(image_to_markdown));
+    parts.push(fence(&format!(
+        "Input: {}\nOutput: {}",
+        example.input, example.output
+    )));
+
+    match example.explanation.split_first() {
+        Some((Block::Paragraph(inlines), rest)) => {
+            parts.push(format!("**Explanation:** {}", inlines_to_markdown(inlines)));
+            parts.push(blocks_to_markdown(rest));
+        }
+        Some(_) => {
+            parts.push("**Explanation:**".to_string());
+            parts.push(blocks_to_markdown(&example.explanation));
+        }
+        None => {}
+    }
+
+    parts.retain(|part| !part.is_empty());
+    parts.join("\n\n")
+}
+
+fn image_to_markdown(image: &Image) -> String {
+    format!("![{}]({})", escape(&image.alt), image.src)
+}
+
+/// Wraps code in a fence long enough not to be closed by its contents.
+fn fence(code: &str) -> String {
+    let mut fence = "```".to_string();
+    while code.contains(&fence) {
+        fence.push('`');
+    }
+
+    format!("{fence}\n{code}\n{fence}")
+}
+
+fn inlines_to_markdown(inlines: &[Inline]) -> String {
+    inlines.iter().map(inline_to_markdown).collect()
+}
+
+fn inline_to_markdown(inline: &Inline) -> String {
+    match inline {
+        Inline::Text(text) => escape(text),
+        Inline::Strong(content) => delimit(&inlines_to_markdown(content), "**"),
+        Inline::Emphasis(content) => delimit(&inlines_to_markdown(content), "*"),
+        Inline::Code(content) => {
+            // Code spans can't hold markup, so superscripts fall back to their plain form.
+            let code = inline_text(content);
+            match code.contains('`') {
+                true => format!("`` {code} ``"),
+                false => format!("`{code}`"),
+            }
+        }
+        Inline::Superscript(content) => format!("<sup>{}</sup>", inlines_to_markdown(content)),
+        Inline::Subscript(content) => format!("<sub>{}</sub>", inlines_to_markdown(content)),
+        Inline::Link { href, content } => format!("[{}]({href})", inlines_to_markdown(content)),
+        Inline::Image(image) => image_to_markdown(image),
+        Inline::LineBreak => "\\\n".to_string(),
+    }
+}
+
+/// Wraps text between delimiters, leaving its surrounding whitespace outside
+/// so Markdown still recognizes them.
+fn delimit(text: &str, delimiter: &str) -> String {
+    let trimmed = text.trim();
+    if trimmed.is_empty() {
+        return text.to_string();
+    }
+
+    let start = text.len() - text.trim_start().len();
+    let end = start + trimmed.len();
+
+    format!(
+        "{}{delimiter}{trimmed}{delimiter}{}",
+        &text[..start],
+        &text[end..]
+    )
+}
+
+/// Escapes the characters Markdown would take as markup.
+fn escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for ch in text.chars() {
+        if matches!(ch, '\\' | '*' | '_' | '`' | '<') {
+            escaped.push('\\');
+        }
+
+        escaped.push(ch);
+    }
+
+    escaped
+}
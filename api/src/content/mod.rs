@@ -0,0 +1,172 @@
+mod constraints;
+mod markdown;
+mod parse;
+mod plain;
+mod scripts;
+
+pub use constraints::{Bound, Limit};
+pub use scripts::{subscript, superscript};
+
+use crate::Question;
+
+/// A problem description, parsed from the HTML LeetCode serves.
+///
+/// Every consumer renders this same tree, so the terminal, the exported
+/// files and the plain text helpers agree on what the description says.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Document {
+    pub blocks: Vec<Block>,
+}
+
+/// A block element of a description.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Block {
+    Paragraph(Vec<Inline>),
+    Heading(Vec<Inline>),
+    Example(Example),
+    Constraints(Vec<Vec<Inline>>),
+    Code(String),
+    Image(Image),
+    List {
+        ordered: bool,
+        items: Vec<Vec<Block>>,
+    },
+    Quote(Vec<Block>),
+    Rule,
+}
+
+/// One of the worked examples of a description.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Example {
+    /// The title, like `Example 1`.
+    pub title: String,
+    pub images: Vec<Image>,
+    pub input: String,
+    pub output: String,
+    pub explanation: Vec<Block>,
+}
+
+/// An image, referenced by its url.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Image {
+    pub src: String,
+    pub alt: String,
+}
+
+/// A piece of text inside a block.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Inline {
+    Text(String),
+    Strong(Vec<Inline>),
+    Emphasis(Vec<Inline>),
+    Code(Vec<Inline>),
+    Superscript(Vec<Inline>),
+    Subscript(Vec<Inline>),
+    Link { href: String, content: Vec<Inline> },
+    Image(Image),
+    LineBreak,
+}
+
+impl Document {
+    /// Parses the HTML of a description.
+    ///
+    /// # Arguments
+    /// * `html` - The description as served by LeetCode.
+    pub fn parse(html: &str) -> Self {
+        Self {
+            blocks: parse::parse_blocks(html),
+        }
+    }
+
+    /// Returns the examples of the description, in order.
+    pub fn examples(&self) -> impl Iterator<Item = &Example> {
+        self.blocks.iter().filter_map(|block| match block {
+            Block::Example(example) => Some(example),
+            _ => None,
+        })
+    }
+
+    /// Returns every image of the description, in order.
+    pub fn images(&self) -> Vec<&Image> {
+        let mut images = Vec::new();
+        collect_images(&self.blocks, &mut images);
+        images
+    }
+
+    /// Renders the description as Markdown.
+    pub fn to_markdown(&self) -> String {
+        markdown::blocks_to_markdown(&self.blocks)
+    }
+
+    /// Renders the description as plain text.
+    pub fn to_plain(&self) -> String {
+        plain::blocks_to_plain(&self.blocks)
+    }
+}
+
+/// Returns the text of some inline elements, without any formatting.
+pub fn inline_text(inlines: &[Inline]) -> String {
+    plain::inlines_to_plain(inlines)
+}
+
+impl Question {
+    /// Parses the description of the question.
+    pub fn document(&self) -> Document {
+        Document::parse(&self.content)
+    }
+}
+
+/// Collects the images found in some blocks, including the ones inside paragraphs.
+fn collect_images<'a>(blocks: &'a [Block], images: &mut Vec<&'a Image>) {
+    for block in blocks {
+        match block {
+            Block::Paragraph(inlines) | Block::Heading(inlines) => {
+                collect_inline_images(inlines, images)
+            }
+            Block::Example(example) => {
+                images.extend(&example.images);
+                collect_images(&example.explanation, images);
+            }
+            Block::Constraints(items) => {
+                for item in items {
+                    collect_inline_images(item, images);
+                }
+            }
+            Block::Image(image) => images.push(image),
+            Block::List { items, .. } => {
+                for item in items {
+                    collect_images(item, images);
+                }
+            }
+            Block::Quote(blocks) => collect_images(blocks, images),
+            Block::Code(_) | Block::Rule => {}
+        }
+    }
+}
+
+fn collect_inline_images<'a>(inlines: &'a [Inline], images: &mut Vec<&'a Image>) {
+    for inline in inlines {
+        match inline {
+            Inline::Image(image) => images.push(image),
+            Inline::Strong(content)
+            | Inline::Emphasis(content)
+            | Inline::Code(content)
+            | Inline::Superscript(content)
+            | Inline::Subscript(content)
+            | Inline::Link { content, .. } => collect_inline_images(content, images),
+            Inline::Text(_) | Inline::LineBreak => {}
+        }
+    }
+}
+
+/// Indents every line of a text but the first one.
+fn indent(text: &str, pad: &str) -> String {
+    text.lines()
+        .enumerate()
+        .map(|(i, line)| match i == 0 || line.is_empty() {
+            true => line.to_string(),
+            false => format!("{pad}{line}"),
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
@@ -0,0 +1,464 @@
+use scraper::{ElementRef, Html, Node};
+
+use super::{Block, Example, Image, Inline, inline_text, subscript, superscript};
+use crate::client::BASE_URL;
+
+/// The labels of the fields of an example.
+const EXAMPLE_LABELS: [&str; 3] = ["Input", "Output", "Explanation"];
+
+/// Parses the HTML of a description into blocks.
+pub(super) fn parse_blocks(html: &str) -> Vec<Block> {
+    let fragment = Html::parse_fragment(html);
+    group(block_children(fragment.root_element()))
+}
+
+/// Collects the block elements inside an element, wrapping loose text into paragraphs.
+fn block_children(el: ElementRef) -> Vec<Block> {
+    let mut blocks = Vec::new();
+    let mut loose = Vec::new();
+
+    for child in el.children() {
+        let Some(child) = ElementRef::wrap(child) else {
+            if let Node::Text(text) = child.value() {
+                loose.push(Inline::Text(text.to_string()));
+            }
+
+            continue;
+        };
+
+        let block = match child.value().name() {
+            "p" => paragraph(inlines(child)),
+            "h1" | "h2" | "h3" | "h4" | "h5" | "h6" => {
+                Some(Block::Heading(normalize(inlines(child))))
+            }
+            "pre" => {
+                let mut code = String::new();
+                preformatted(child, &mut code);
+                Some(Block::Code(
+                    code.trim_start_matches('\n').trim_end().to_string(),
+                ))
+            }
+            "ul" | "ol" => Some(Block::List {
+                ordered: child.value().name() == "ol",
+                items: child
+                    .child_elements()
+                    .filter(|item| item.value().name() == "li")
+                    .map(block_children)
+                    .collect(),
+            }),
+            "blockquote" => Some(Block::Quote(block_children(child))),
+            "hr" => Some(Block::Rule),
+            "div" | "section" | "article" | "body" | "html" => {
+                let inner = block_children(child);
+                let example = child
+                    .value()
+                    .classes()
+                    .any(|class| class == "example-block")
+                    .then(|| example_from_blocks(&inner))
+                    .flatten();
+
+                flush(&mut blocks, &mut loose);
+                match example {
+                    Some(example) => blocks.push(Block::Example(example)),
+                    None => blocks.extend(inner),
+                }
+
+                continue;
+            }
+            "script" | "style" => continue,
+            _ => {
+                loose.extend(inline(child));
+                continue;
+            }
+        };
+
+        flush(&mut blocks, &mut loose);
+        blocks.extend(block);
+    }
+
+    flush(&mut blocks, &mut loose);
+    blocks
+}
+
+/// Turns the loose inline elements found so far into a paragraph.
+fn flush(blocks: &mut Vec<Block>, loose: &mut Vec<Inline>) {
+    blocks.extend(paragraph(std::mem::take(loose)));
+}
+
+/// Builds a paragraph out of inline elements, or an image if that's all it holds.
+fn paragraph(inlines: Vec<Inline>) -> Option<Block> {
+    let inlines = normalize(inlines);
+
+    match inlines.as_slice() {
+        [] => None,
+        [Inline::Image(image)] => Some(Block::Image(image.clone())),
+        _ => Some(Block::Paragraph(inlines)),
+    }
+}
+
+/// Collects the inline elements inside an element.
+fn inlines(el: ElementRef) -> Vec<Inline> {
+    let mut inlines = Vec::new();
+
+    for child in el.children() {
+        match ElementRef::wrap(child) {
+            Some(child) => inlines.extend(inline(child)),
+            None => {
+                if let Node::Text(text) = child.value() {
+                    inlines.push(Inline::Text(text.to_string()));
+                }
+            }
+        }
+    }
+
+    inlines
+}
+
+/// Converts an inline element, unwrapping the ones without meaning like `<span>`.
+fn inline(el: ElementRef) -> Vec<Inline> {
+    let inline = match el.value().name() {
+        "strong" | "b" => Inline::Strong(inlines(el)),
+        "em" | "i" => Inline::Emphasis(inlines(el)),
+        "code" | "tt" | "kbd" => Inline::Code(inlines(el)),
+        "sup" => Inline::Superscript(inlines(el)),
+        "sub" => Inline::Subscript(inlines(el)),
+        "a" => Inline::Link {
+            href: el.attr("href").unwrap_or_default().to_string(),
+            content: inlines(el),
+        },
+        "img" => Inline::Image(image(el)),
+        "br" => Inline::LineBreak,
+        "script" | "style" => return Vec::new(),
+        _ => return inlines(el),
+    };
+
+    vec![inline]
+}
+
+/// Reads the url and description of an `<img>` element.
+fn image(el: ElementRef) -> Image {
+    let src = el.attr("src").unwrap_or_default();
+
+    // Images may be referenced relative to LeetCode's site.
+    let src = if src.starts_with("//") {
+        format!("https:{src}")
+    } else if src.starts_with('/') {
+        format!("{BASE_URL}{src}")
+    } else {
+        src.to_string()
+    };
+
+    Image {
+        src,
+        alt: el.attr("alt").unwrap_or_default().trim().to_string(),
+    }
+}
+
+/// Collects the text of a `<pre>` element, keeping its whitespace.
+fn preformatted(el: ElementRef, out: &mut String) {
+    for child in el.children() {
+        let Some(child) = ElementRef::wrap(child) else {
+            if let Node::Text(text) = child.value() {
+                out.push_str(text);
+            }
+
+            continue;
+        };
+
+        match child.value().name() {
+            "br" => out.push('\n'),
+            name @ ("sup" | "sub") => {
+                let mut inner = String::new();
+                preformatted(child, &mut inner);
+                out.push_str(&match name {
+                    "sup" => superscript(&inner),
+                    _ => subscript(&inner),
+                });
+            }
+            _ => preformatted(child, out),
+        }
+    }
+}
+
+/// Collapses whitespace the way browsers do, trimming both ends.
+fn normalize(inlines: Vec<Inline>) -> Vec<Inline> {
+    let mut space = true;
+    let mut inlines = collapse(inlines, &mut space);
+    trim_end(&mut inlines, true);
+    inlines
+}
+
+/// Collapses runs of whitespace into a single space.
+///
+/// # Arguments
+/// * `inlines` - The elements to collapse.
+/// * `space` - Whether the text written so far ends in whitespace.
+fn collapse(inlines: Vec<Inline>, space: &mut bool) -> Vec<Inline> {
+    let mut out: Vec<Inline> = Vec::new();
+
+    for mut inline in inlines {
+        match &mut inline {
+            Inline::Text(text) => {
+                let mut collapsed = String::new();
+                for ch in text.chars() {
+                    if !ch.is_whitespace() {
+                        collapsed.push(ch);
+                        *space = false;
+                    } else if !*space {
+                        collapsed.push(' ');
+                        *space = true;
+                    }
+                }
+
+                match out.last_mut() {
+                    _ if collapsed.is_empty() => {}
+                    Some(Inline::Text(prev)) => prev.push_str(&collapsed),
+                    _ => out.push(Inline::Text(collapsed)),
+                }
+
+                continue;
+            }
+            Inline::LineBreak => {
+                trim_end(&mut out, false);
+                *space = true;
+            }
+            Inline::Image(_) => *space = false,
+            Inline::Strong(content)
+            | Inline::Emphasis(content)
+            | Inline::Code(content)
+            | Inline::Superscript(content)
+            | Inline::Subscript(content)
+            | Inline::Link { content, .. } => {
+                *content = collapse(std::mem::take(content), space);
+                if content.is_empty() {
+                    continue;
+                }
+            }
+        }
+
+        out.push(inline);
+    }
+
+    out
+}
+
+/// Removes the whitespace at the end of some inline elements.
+///
+/// # Arguments
+/// * `inlines` - The elements to trim.
+/// * `breaks` - Whether trailing line breaks should be removed too.
+fn trim_end(inlines: &mut Vec<Inline>, breaks: bool) {
+    while let Some(last) = inlines.last_mut() {
+        let empty = match last {
+            Inline::Text(text) => {
+                text.truncate(text.trim_end().len());
+                text.is_empty()
+            }
+            Inline::LineBreak => breaks,
+            Inline::Image(_) => false,
+            Inline::Strong(content)
+            | Inline::Emphasis(content)
+            | Inline::Code(content)
+            | Inline::Superscript(content)
+            | Inline::Subscript(content)
+            | Inline::Link { content, .. } => {
+                trim_end(content, breaks);
+                content.is_empty()
+            }
+        };
+
+        if !empty {
+            break;
+        }
+
+        inlines.pop();
+    }
+}
+
+/// Splits a paragraph starting with a bold label, like `<strong>Input:</strong> x`.
+///
+/// # Returns
+/// The label without its colon and the rest of the paragraph.
+fn labeled(inlines: &[Inline]) -> Option<(String, Vec<Inline>)> {
+    let (Inline::Strong(label), rest) = inlines.split_first()? else {
+        return None;
+    };
+
+    let label = inline_text(label);
+    let label = label.trim().strip_suffix(':')?.trim_end();
+
+    Some((label.to_string(), normalize(rest.to_vec())))
+}
+
+/// Reads an example out of the paragraphs of an example box.
+fn example_from_blocks(blocks: &[Block]) -> Option<Example> {
+    let mut example = Example::default();
+    let (mut input, mut output) = (None, None);
+
+    for block in blocks {
+        let field = match block {
+            Block::Paragraph(inlines) => labeled(inlines),
+            Block::Image(image) if input.is_none() => {
+                example.images.push(image.clone());
+                continue;
+            }
+            _ => None,
+        };
+
+        match field {
+            Some((label, rest)) if label == "Input" => input = Some(inline_text(&rest)),
+            Some((label, rest)) if label == "Output" => output = Some(inline_text(&rest)),
+            Some((label, rest)) if label == "Explanation" => {
+                if !rest.is_empty() {
+                    example.explanation.push(Block::Paragraph(rest));
+                }
+            }
+            _ => example.explanation.push(block.clone()),
+        }
+    }
+
+    example.input = input?;
+    example.output = output?;
+    Some(example)
+}
+
+/// Reads an example out of the `Input: ...`, `Output: ...` lines of a `<pre>` block.
+fn example_from_text(text: &str) -> Option<Example> {
+    let mut fields: Vec<(&str, Vec<&str>)> = Vec::new();
+
+    for line in text.lines().map(str::trim) {
+        let field = EXAMPLE_LABELS.iter().find_map(|label| {
+            let value = line.strip_prefix(label)?.strip_prefix(':')?;
+            Some((*label, value.trim()))
+        });
+
+        match (field, fields.last_mut()) {
+            (Some((label, value)), _) => fields.push((label, vec![value])),
+            (None, Some((_, lines))) => lines.push(line),
+            (None, None) if line.is_empty() => {}
+            (None, None) => return None,
+        }
+    }
+
+    let field = |label: &str| {
+        fields
+            .iter()
+            .find(|(l, _)| *l == label)
+            .map(|(_, lines)| lines.clone())
+    };
+
+    let mut explanation = Vec::new();
+    for line in field("Explanation").unwrap_or_default() {
+        if !explanation.is_empty() {
+            explanation.push(Inline::LineBreak);
+        }
+
+        explanation.push(Inline::Text(line.to_string()));
+    }
+
+    let explanation = normalize(explanation);
+
+    Some(Example {
+        input: field("Input")?.join("\n"),
+        output: field("Output")?.join("\n"),
+        explanation: match explanation.is_empty() {
+            true => Vec::new(),
+            false => vec![Block::Paragraph(explanation)],
+        },
+        ..Default::default()
+    })
+}
+
+/// Returns the title of a paragraph like `Example 1:`.
+fn example_title(block: &Block) -> Option<String> {
+    let Block::Paragraph(inlines) = block else {
+        return None;
+    };
+
+    let text = inline_text(inlines);
+    let title = text.trim().trim_end_matches(':').trim_end();
+    let number = title.strip_prefix("Example")?;
+
+    number
+        .trim()
+        .chars()
+        .all(|ch| ch.is_ascii_digit())
+        .then(|| title.to_string())
+}
+
+/// Checks whether a block is a paragraph holding just a label, like `Constraints:`.
+fn is_label(block: &Block, label: &str) -> bool {
+    match block {
+        Block::Paragraph(inlines) => inline_text(inlines)
+            .trim()
+            .strip_suffix(':')
+            .is_some_and(|text| text.trim_end() == label),
+        _ => false,
+    }
+}
+
+/// Recognizes the examples and constraints among the top level blocks.
+fn group(blocks: Vec<Block>) -> Vec<Block> {
+    let mut grouped = Vec::new();
+    let mut blocks = blocks.into_iter().peekable();
+
+    while let Some(block) = blocks.next() {
+        if let Some(title) = example_title(&block) {
+            let mut images = Vec::new();
+            while let Some(Block::Image(image)) = blocks.peek() {
+                images.push(image.clone());
+                blocks.next();
+            }
+
+            let example = match blocks.peek() {
+                Some(Block::Code(code)) => example_from_text(code),
+                Some(Block::Example(example)) => Some(example.clone()),
+                _ => None,
+            };
+
+            match example {
+                Some(mut example) => {
+                    blocks.next();
+                    images.append(&mut example.images);
+                    grouped.push(Block::Example(Example {
+                        title,
+                        images,
+                        ..example
+                    }));
+                }
+                None => {
+                    grouped.push(block);
+                    grouped.extend(images.into_iter().map(Block::Image));
+                }
+            }
+
+            continue;
+        }
+
+        if is_label(&block, "Constraints")
+            && let Some(Block::List {
+                ordered: false,
+                items,
+            }) = blocks.peek()
+            && items
+                .iter()
+                .all(|item| matches!(item.as_slice(), [Block::Paragraph(_)]))
+        {
+            let items = items
+                .iter()
+                .filter_map(|item| match item.as_slice() {
+                    [Block::Paragraph(inlines)] => Some(inlines.clone()),
+                    _ => None,
+                })
+                .collect();
+
+            blocks.next();
+            grouped.push(Block::Constraints(items));
+            continue;
+        }
+
+        grouped.push(block);
+    }
+
+    grouped
+}
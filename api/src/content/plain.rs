@@ -0,0 +1,85 @@
+use super::{Block, Example, Image, Inline, indent, subscript, superscript};
+
+/// Renders blocks as plain text, separated by blank lines.
+pub(super) fn blocks_to_plain(blocks: &[Block]) -> String {
+    blocks
+        .iter()
+        .map(block_to_plain)
+        .collect::<Vec<_>>()
+        .join("\n\n")
+}
+
+fn block_to_plain(block: &Block) -> String {
+    match block {
+        Block::Paragraph(inlines) | Block::Heading(inlines) => inlines_to_plain(inlines),
+        Block::Example(example) => example_to_plain(example),
+        Block::Constraints(items) => {
+            let items: Vec<_> = items
+                .iter()
+                .map(|item| format!("- {}", indent(&inlines_to_plain(item), "  ")))
+                .collect();
+
+            format!("Constraints:\n{}", items.join("\n"))
+        }
+        Block::Code(code) => code.clone(),
+        Block::Image(image) => image_to_plain(image),
+        Block::List { ordered, items } => items
+            .iter()
+            .enumerate()
+            .map(|(i, item)| {
+                let marker = match ordered {
+                    true => format!("{}. ", i + 1),
+                    false => "- ".to_string(),
+                };
+
+                let pad = " ".repeat(marker.len());
+                format!("{marker}{}", indent(&blocks_to_plain(item), &pad))
+            })
+            .collect::<Vec<_>>()
+            .join("\n"),
+        Block::Quote(blocks) => format!("  {}", indent(&blocks_to_plain(blocks), "  ")),
+        Block::Rule => "---".to_string(),
+    }
+}
+
+fn example_to_plain(example: &Example) -> String {
+    let mut lines = vec![format!("{}:", example.title)];
+    lines.extend(example.images.iter().map(image_to_plain));
+    lines.push(format!("Input: {}", example.input));
+    lines.push(format!("Output: {}", example.output));
+
+    if !example.explanation.is_empty() {
+        let explanation = blocks_to_plain(&example.explanation);
+        match example.explanation.first() {
+            Some(Block::Paragraph(_)) => lines.push(format!("Explanation: {explanation}")),
+            _ => lines.push(format!("Explanation:\n{explanation}")),
+        }
+    }
+
+    lines.join("\n")
+}
+
+fn image_to_plain(image: &Image) -> String {
+    match image.alt.is_empty() {
+        true => "[image]".to_string(),
+        false => format!("[image: {}]", image.alt),
+    }
+}
+
+/// Renders inline elements as plain text.
+pub(super) fn inlines_to_plain(inlines: &[Inline]) -> String {
+    inlines
+        .iter()
+        .map(|inline| match inline {
+            Inline::Text(text) => text.clone(),
+            Inline::Strong(content)
+            | Inline::Emphasis(content)
+            | Inline::Code(content)
+            | Inline::Link { content, .. } => inlines_to_plain(content),
+            Inline::Superscript(content) => superscript(&inlines_to_plain(content)),
+            Inline::Subscript(content) => subscript(&inlines_to_plain(content)),
+            Inline::Image(image) => image_to_plain(image),
+            Inline::LineBreak => "\n".to_string(),
+        })
+        .collect()
+}
@@ -0,0 +1,120 @@
+/// Writes text as superscript, like the exponent in `10⁵`.
+///
+/// Falls back to a caret, like `10^q`, when some character has no
+/// superscript form, wrapping the text in parentheses if it isn't a single word.
+pub fn superscript(text: &str) -> String {
+    convert(text, '^', |ch| {
+        Some(match ch {
+            '0' => '⁰',
+            '1' => '¹',
+            '2' => '²',
+            '3' => '³',
+            '4' => '⁴',
+            '5' => '⁵',
+            '6' => '⁶',
+            '7' => '⁷',
+            '8' => '⁸',
+            '9' => '⁹',
+            '+' => '⁺',
+            '-' | '−' => '⁻',
+            '=' => '⁼',
+            '(' => '⁽',
+            ')' => '⁾',
+            'a' => 'ᵃ',
+            'b' => 'ᵇ',
+            'c' => 'ᶜ',
+            'd' => 'ᵈ',
+            'e' => 'ᵉ',
+            'f' => 'ᶠ',
+            'g' => 'ᵍ',
+            'h' => 'ʰ',
+            'i' => 'ⁱ',
+            'j' => 'ʲ',
+            'k' => 'ᵏ',
+            'l' => 'ˡ',
+            'm' => 'ᵐ',
+            'n' => 'ⁿ',
+            'o' => 'ᵒ',
+            'p' => 'ᵖ',
+            'r' => 'ʳ',
+            's' => 'ˢ',
+            't' => 'ᵗ',
+            'u' => 'ᵘ',
+            'v' => 'ᵛ',
+            'w' => 'ʷ',
+            'x' => 'ˣ',
+            'y' => 'ʸ',
+            'z' => 'ᶻ',
+            _ => return None,
+        })
+    })
+}
+
+/// Writes text as subscript, like the index in `xᵢ`.
+///
+/// Falls back to an underscore, like `x_(b)`, when some character has no
+/// subscript form, wrapping the text in parentheses if it isn't a single word.
+pub fn subscript(text: &str) -> String {
+    convert(text, '_', |ch| {
+        Some(match ch {
+            '0' => '₀',
+            '1' => '₁',
+            '2' => '₂',
+            '3' => '₃',
+            '4' => '₄',
+            '5' => '₅',
+            '6' => '₆',
+            '7' => '₇',
+            '8' => '₈',
+            '9' => '₉',
+            '+' => '₊',
+            '-' | '−' => '₋',
+            '=' => '₌',
+            '(' => '₍',
+            ')' => '₎',
+            'a' => 'ₐ',
+            'e' => 'ₑ',
+            'h' => 'ₕ',
+            'i' => 'ᵢ',
+            'j' => 'ⱼ',
+            'k' => 'ₖ',
+            'l' => 'ₗ',
+            'm' => 'ₘ',
+            'n' => 'ₙ',
+            'o' => 'ₒ',
+            'p' => 'ₚ',
+            'r' => 'ᵣ',
+            's' => 'ₛ',
+            't' => 'ₜ',
+            'u' => 'ᵤ',
+            'v' => 'ᵥ',
+            'x' => 'ₓ',
+            _ => return None,
+        })
+    })
+}
+
+/// Maps every character of a text, ignoring whitespace, or marks it
+/// with a prefix if some of them can't be mapped.
+fn convert(text: &str, marker: char, map: impl Fn(char) -> Option<char>) -> String {
+    let text = text.trim();
+    let mapped: Option<String> = text
+        .chars()
+        .filter(|ch| !ch.is_whitespace())
+        .map(&map)
+        .collect();
+
+    match mapped {
+        Some(mapped) => mapped,
+        None => marked(text, marker),
+    }
+}
+
+/// Writes text after a marker like `^`, wrapping it in parentheses if it isn't a single word.
+pub(super) fn marked(text: &str, marker: char) -> String {
+    let text = text.trim();
+    match text.chars().all(|ch| ch.is_alphanumeric() || ch == '.') {
+        true => format!("{marker}{text}"),
+        false => format!("{marker}({text})"),
+    }
+}
@@ -1,7 +1,9 @@
 mod client;
+mod content;
 mod error;
 mod models;
 
 pub use client::LeetCodeClient;
+pub use content::*;
 pub use error::{LeetCodeErr, Result};
 pub use models::*;
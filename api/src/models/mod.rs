@@ -1,6 +1,7 @@
 mod daily;
 mod feed;
 mod graphql;
+mod param_type;
 mod profile;
 mod question;
 mod submission;
@@ -8,6 +9,7 @@ mod submission;
 pub use daily::*;
 pub use feed::*;
 pub use graphql::*;
+pub use param_type::*;
 pub use profile::*;
 pub use question::*;
 pub use submission::*;
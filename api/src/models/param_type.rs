@@ -0,0 +1,282 @@
+use std::fmt::{self, Display};
+
+use serde_json::Value;
+
+use super::Param;
+
+/// The type of a parameter as described by the question's metadata.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum ParamType {
+    Integer,
+    Long,
+    Double,
+    Boolean,
+    Character,
+    String,
+    Array(Box<ParamType>),
+    ListNode,
+    TreeNode,
+    Unknown(String),
+}
+
+impl ParamType {
+    /// Parses a type name like `integer[]` or `list<list<string>>`.
+    ///
+    /// # Arguments
+    /// * `name` - The type name found in the metadata.
+    pub fn parse(name: &str) -> Self {
+        let name = name.trim();
+
+        if let Some(inner) = name.strip_suffix("[]") {
+            return ParamType::Array(Box::new(Self::parse(inner)));
+        }
+
+        if let Some(inner) = name
+            .strip_prefix("list<")
+            .and_then(|rest| rest.strip_suffix('>'))
+        {
+            return ParamType::Array(Box::new(Self::parse(inner)));
+        }
+
+        match name {
+            "integer" => ParamType::Integer,
+            "long" => ParamType::Long,
+            "double" => ParamType::Double,
+            "boolean" => ParamType::Boolean,
+            "character" => ParamType::Character,
+            "string" => ParamType::String,
+            "ListNode" => ParamType::ListNode,
+            "TreeNode" => ParamType::TreeNode,
+            _ => ParamType::Unknown(name.to_string()),
+        }
+    }
+
+    /// Checks that a test case value is a valid instance of this type.
+    ///
+    /// # Arguments
+    /// * `value` - The value as typed in LeetCode's input format.
+    ///
+    /// # Returns
+    /// A message describing the problem if the value is invalid.
+    pub fn validate(&self, value: &str) -> Result<(), String> {
+        let value = value.trim();
+        if value.is_empty() {
+            return Err("value is empty".into());
+        }
+
+        if let ParamType::Unknown(_) = self {
+            return Ok(());
+        }
+
+        let json: Value = match serde_json::from_str(value) {
+            Ok(json) => json,
+            Err(_) if !value.starts_with('[') => return Err(format!("expected {self}")),
+            Err(e) => {
+                // Point at the syntax error inside arrays, where it's hard to spot.
+                let msg = e.to_string();
+                let msg = msg.split(" at line").next().unwrap_or_default();
+                return Err(format!("{msg} at column {}", e.column()));
+            }
+        };
+
+        if self.matches(&json) {
+            Ok(())
+        } else {
+            Err(format!("expected {self}"))
+        }
+    }
+
+    /// Checks whether a parsed value has the shape of this type.
+    fn matches(&self, value: &Value) -> bool {
+        match (self, value) {
+            (ParamType::Integer, Value::Number(n)) => {
+                n.as_i64().is_some_and(|n| i32::try_from(n).is_ok())
+            }
+            (ParamType::Long, Value::Number(n)) => n.is_i64(),
+            (ParamType::Double, Value::Number(_)) => true,
+            (ParamType::Boolean, Value::Bool(_)) => true,
+            (ParamType::Character, Value::String(s)) => s.chars().count() == 1,
+            (ParamType::String, Value::String(_)) => true,
+            (ParamType::Array(inner), Value::Array(items)) => {
+                items.iter().all(|item| inner.matches(item))
+            }
+            (ParamType::ListNode, Value::Array(items)) => {
+                items.iter().all(|item| ParamType::Integer.matches(item))
+            }
+            (ParamType::TreeNode, Value::Array(items)) => items
+                .iter()
+                .all(|item| item.is_null() || ParamType::Integer.matches(item)),
+            (ParamType::Unknown(_), _) => true,
+            _ => false,
+        }
+    }
+
+    /// Describes several values of this type, for nested array messages.
+    fn plural(&self) -> String {
+        match self {
+            ParamType::Integer => "integers".into(),
+            ParamType::Long => "long integers".into(),
+            ParamType::Double => "numbers".into(),
+            ParamType::Boolean => "booleans".into(),
+            ParamType::Character => "quoted characters".into(),
+            ParamType::String => "quoted strings".into(),
+            ParamType::Array(inner) => format!("arrays of {}", inner.plural()),
+            ParamType::ListNode => "lists".into(),
+            ParamType::TreeNode => "trees".into(),
+            ParamType::Unknown(name) => format!("{name} values"),
+        }
+    }
+}
+
+impl Display for ParamType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParamType::Integer => f.write_str("an integer"),
+            ParamType::Long => f.write_str("a long integer"),
+            ParamType::Double => f.write_str("a number"),
+            ParamType::Boolean => f.write_str("true or false"),
+            ParamType::Character => f.write_str("a quoted character, like \"a\""),
+            ParamType::String => f.write_str("a quoted string, like \"abc\""),
+            ParamType::Array(inner) => write!(f, "an array of {}", inner.plural()),
+            ParamType::ListNode => f.write_str("a list, like [1,2,3]"),
+            ParamType::TreeNode => f.write_str("a tree, like [1,null,2]"),
+            ParamType::Unknown(name) => write!(f, "a {name}"),
+        }
+    }
+}
+
+impl Param {
+    /// Returns the parsed type of the parameter.
+    pub fn kind(&self) -> ParamType {
+        ParamType::parse(&self.param_type)
+    }
+
+    /// Checks that a test case value is valid for this parameter.
+    ///
+    /// # Arguments
+    /// * `value` - The value as typed in LeetCode's input format.
+    ///
+    /// # Returns
+    /// A message describing the problem if the value is invalid.
+    pub fn validate(&self, value: &str) -> Result<(), String> {
+        self.kind().validate(value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Wraps a type in an array.
+    fn array(inner: ParamType) -> ParamType {
+        ParamType::Array(Box::new(inner))
+    }
+
+    #[test]
+    fn parses_scalars() {
+        assert_eq!(ParamType::parse("integer"), ParamType::Integer);
+        assert_eq!(ParamType::parse("long"), ParamType::Long);
+        assert_eq!(ParamType::parse("double"), ParamType::Double);
+        assert_eq!(ParamType::parse("boolean"), ParamType::Boolean);
+        assert_eq!(ParamType::parse("character"), ParamType::Character);
+        assert_eq!(ParamType::parse(" string "), ParamType::String);
+        assert_eq!(ParamType::parse("ListNode"), ParamType::ListNode);
+        assert_eq!(ParamType::parse("TreeNode"), ParamType::TreeNode);
+    }
+
+    #[test]
+    fn parses_arrays_and_lists() {
+        assert_eq!(ParamType::parse("integer[]"), array(ParamType::Integer));
+        assert_eq!(
+            ParamType::parse("character[][]"),
+            array(array(ParamType::Character))
+        );
+        assert_eq!(
+            ParamType::parse("list<list<string>>"),
+            array(array(ParamType::String))
+        );
+        assert_eq!(
+            ParamType::parse("list<integer[]>"),
+            array(array(ParamType::Integer))
+        );
+    }
+
+    #[test]
+    fn keeps_unknown_names() {
+        assert_eq!(
+            ParamType::parse("Node"),
+            ParamType::Unknown("Node".to_string())
+        );
+        assert_eq!(
+            ParamType::parse("list<string"),
+            ParamType::Unknown("list<string".to_string())
+        );
+    }
+
+    #[test]
+    fn validates_numbers() {
+        assert!(ParamType::Integer.validate("-42").is_ok());
+        assert!(ParamType::Integer.validate("2147483648").is_err());
+        assert!(ParamType::Integer.validate("1.5").is_err());
+        assert!(ParamType::Long.validate("2147483648").is_ok());
+        assert!(ParamType::Long.validate("\"1\"").is_err());
+        assert!(ParamType::Double.validate("1.5").is_ok());
+        assert!(ParamType::Double.validate("abc").is_err());
+    }
+
+    #[test]
+    fn validates_booleans() {
+        assert!(ParamType::Boolean.validate("true").is_ok());
+        assert!(ParamType::Boolean.validate("1").is_err());
+    }
+
+    #[test]
+    fn validates_characters_and_strings() {
+        assert!(ParamType::Character.validate("\"a\"").is_ok());
+        assert!(ParamType::Character.validate("\"ab\"").is_err());
+        assert!(ParamType::Character.validate("a").is_err());
+        assert!(ParamType::String.validate("\"abc\"").is_ok());
+        assert_eq!(
+            ParamType::String.validate("abc"),
+            Err("expected a quoted string, like \"abc\"".to_string())
+        );
+    }
+
+    #[test]
+    fn validates_arrays() {
+        let grid = array(array(ParamType::Integer));
+        assert!(grid.validate("[[1,2],[3]]").is_ok());
+        assert!(grid.validate("[]").is_ok());
+        assert_eq!(
+            grid.validate("[1,2]"),
+            Err("expected an array of arrays of integers".to_string())
+        );
+        assert!(array(ParamType::String).validate("[\"a\",1]").is_err());
+    }
+
+    #[test]
+    fn points_at_syntax_errors_in_arrays() {
+        let err = array(ParamType::Integer).validate("[1,2,]").unwrap_err();
+        assert!(err.ends_with("at column 6"), "{err}");
+
+        let err = array(ParamType::Integer).validate("[1 2]").unwrap_err();
+        assert!(err.ends_with("at column 4"), "{err}");
+    }
+
+    #[test]
+    fn validates_lists_and_trees() {
+        assert!(ParamType::ListNode.validate("[1,2,3]").is_ok());
+        assert!(ParamType::ListNode.validate("[1,null]").is_err());
+        assert!(ParamType::TreeNode.validate("[1,null,2]").is_ok());
+        assert!(ParamType::TreeNode.validate("[\"a\"]").is_err());
+        assert!(ParamType::TreeNode.validate("1").is_err());
+    }
+
+    #[test]
+    fn accepts_anything_but_empty_for_unknown_types() {
+        let unknown = ParamType::Unknown("Node".to_string());
+        assert!(unknown.validate("[[2,4],[1,3]]").is_ok());
+        assert!(unknown.validate("not json").is_ok());
+        assert_eq!(unknown.validate("  "), Err("value is empty".to_string()));
+    }
+}
@@ -79,7 +79,7 @@ pub struct ProblemSummary {
     pub topic_tags: Vec<TopicTag>,
 }
 
-#[derive(Debug, Deserialize, PartialEq, Eq)]
+#[derive(Debug, Deserialize, PartialEq, Eq, Clone, Copy)]
 #[serde(rename_all = "PascalCase")]
 pub enum Difficulty {
     Easy,
@@ -87,6 +87,17 @@ pub enum Difficulty {
     Hard,
 }
 
+impl Difficulty {
+    /// Returns the difficulty as the problem list filters expect it.
+    pub fn filter_value(self) -> &'static str {
+        match self {
+            Difficulty::Easy => "EASY",
+            Difficulty::Medium => "MEDIUM",
+            Difficulty::Hard => "HARD",
+        }
+    }
+}
+
 #[derive(Debug, Deserialize, PartialEq, Eq)]
 pub enum ProblemStatus {
     #[serde(rename = "ac")]
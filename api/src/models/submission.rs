@@ -1,3 +1,5 @@
+use std::fmt::{self, Display};
+
 use serde::Deserialize;
 
 use crate::Language;
@@ -99,6 +101,23 @@ pub enum StatusMsg {
     Unknown,
 }
 
+impl Display for StatusMsg {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let s = match self {
+            StatusMsg::Accepted => "Accepted",
+            StatusMsg::WrongAnswer => "Wrong Answer",
+            StatusMsg::CompileError => "Compile Error",
+            StatusMsg::RuntimeError => "Runtime Error",
+            StatusMsg::TimeLimitExceeded => "Time Limit Exceeded",
+            StatusMsg::MemoryLimitExceeded => "Memory Limit Exceeded",
+            StatusMsg::InternalError => "Internal Error",
+            StatusMsg::Unknown => "Unknown",
+        };
+
+        f.write_str(s)
+    }
+}
+
 #[derive(Debug, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct SubmissionListResponse {
@@ -108,14 +127,14 @@ pub struct SubmissionListResponse {
 #[derive(Debug, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct QuestionSubmissionList {
-    submissions: Vec<Submission>,
+    pub submissions: Vec<Submission>,
 }
 
 #[derive(Debug, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct Submission {
-    id: String,
-    lang: Language,
-    status_display: String,
-    code: String,
+    pub id: String,
+    pub lang: Language,
+    pub status_display: String,
+    pub code: String,
 }
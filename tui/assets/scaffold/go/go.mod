@@ -0,0 +1,3 @@
+module leetui/{{name}}
+
+go 1.22
@@ -0,0 +1,10 @@
+//! Generated by leetui so rust-analyzer can check `{{solution}}`, which is submitted as is.
+#![allow(dead_code)]
+
+mod leetcode;
+
+pub use leetcode::*;
+
+pub struct Solution;
+
+include!("{{solution}}");
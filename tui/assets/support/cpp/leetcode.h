@@ -0,0 +1,124 @@
+// Local definitions of the data structures LeetCode provides to C++ solutions.
+//
+// Generated by leetui, this file is never submitted. It is force-included into
+// every translation unit through `.clangd`, just like LeetCode does.
+#pragma once
+
+#include <bits/stdc++.h>
+
+using namespace std;
+
+// Definition for singly-linked list.
+struct ListNode {
+    int val;
+    ListNode *next;
+    ListNode() : val(0), next(nullptr) {}
+    ListNode(int x) : val(x), next(nullptr) {}
+    ListNode(int x, ListNode *next) : val(x), next(next) {}
+};
+
+// Definition for a binary tree node.
+struct TreeNode {
+    int val;
+    TreeNode *left;
+    TreeNode *right;
+    TreeNode() : val(0), left(nullptr), right(nullptr) {}
+    TreeNode(int x) : val(x), left(nullptr), right(nullptr) {}
+    TreeNode(int x, TreeNode *left, TreeNode *right) : val(x), left(left), right(right) {}
+};
+
+// Parses LeetCode's array notation, e.g. `[3,9,20,null,null,15,7]`.
+inline vector<optional<int>> parseValues(const string &s) {
+    vector<optional<int>> values;
+    string token;
+    stringstream ss(s.substr(s.find('[') + 1, s.rfind(']') - s.find('[') - 1));
+
+    while (getline(ss, token, ',')) {
+        token.erase(0, token.find_first_not_of(" \t"));
+        token.erase(token.find_last_not_of(" \t") + 1);
+        if (token.empty()) continue;
+        values.push_back(token == "null" ? nullopt : optional<int>(stoi(token)));
+    }
+
+    return values;
+}
+
+// Builds a linked list out of LeetCode's array notation, e.g. `[1,2,3]`.
+inline ListNode *buildList(const string &s) {
+    ListNode dummy;
+    ListNode *tail = &dummy;
+    for (auto &val : parseValues(s)) {
+        if (!val) continue;
+        tail->next = new ListNode(*val);
+        tail = tail->next;
+    }
+
+    return dummy.next;
+}
+
+// Builds a binary tree out of LeetCode's array notation, e.g. `[1,null,2,3]`.
+inline TreeNode *buildTree(const string &s) {
+    auto values = parseValues(s);
+    if (values.empty() || !values[0]) return nullptr;
+
+    size_t i = 1;
+    TreeNode *root = new TreeNode(*values[0]);
+    queue<TreeNode *> q;
+    q.push(root);
+
+    while (!q.empty() && i < values.size()) {
+        TreeNode *node = q.front();
+        q.pop();
+
+        for (TreeNode **child : {&node->left, &node->right}) {
+            if (i >= values.size()) break;
+            if (auto val = values[i++]) {
+                *child = new TreeNode(*val);
+                q.push(*child);
+            }
+        }
+    }
+
+    return root;
+}
+
+// Prints a linked list in LeetCode's array notation.
+inline string listToString(ListNode *head) {
+    string s = "[";
+    for (; head; head = head->next) {
+        s += to_string(head->val);
+        if (head->next) s += ",";
+    }
+
+    return s + "]";
+}
+
+// Prints a binary tree in LeetCode's array notation, without trailing nulls.
+inline string treeToString(TreeNode *root) {
+    vector<string> values;
+    queue<TreeNode *> q;
+    q.push(root);
+
+    while (!q.empty()) {
+        TreeNode *node = q.front();
+        q.pop();
+        if (!node) {
+            values.push_back("null");
+            continue;
+        }
+
+        values.push_back(to_string(node->val));
+        q.push(node->left);
+        q.push(node->right);
+    }
+
+    while (!values.empty() && values.back() == "null") values.pop_back();
+
+    string s = "[";
+    for (size_t i = 0; i < values.size(); i++) {
+        if (i) s += ",";
+        s += values[i];
+    }
+
+    return s + "]";
+}
@@ -0,0 +1,91 @@
+// Local definitions of the data structures LeetCode provides to Go solutions.
+//
+// Generated by leetui, this file is never submitted.
+package main
+
+import (
+	"strconv"
+	"strings"
+)
+
+// Definition for singly-linked list.
+type ListNode struct {
+	Val  int
+	Next *ListNode
+}
+
+// Definition for a binary tree node.
+type TreeNode struct {
+	Val   int
+	Left  *TreeNode
+	Right *TreeNode
+}
+
+// ParseValues parses LeetCode's array notation, e.g. `[3,9,20,null,null,15,7]`.
+func ParseValues(s string) []*int {
+	var values []*int
+	inner := strings.TrimSuffix(strings.TrimPrefix(strings.TrimSpace(s), "["), "]")
+	for _, token := range strings.Split(inner, ",") {
+		token = strings.TrimSpace(token)
+		if token == "" {
+			continue
+		}
+
+		if token == "null" {
+			values = append(values, nil)
+			continue
+		}
+
+		val, _ := strconv.Atoi(token)
+		values = append(values, &val)
+	}
+
+	return values
+}
+
+// BuildList builds a linked list out of LeetCode's array notation, e.g. `[1,2,3]`.
+func BuildList(s string) *ListNode {
+	dummy := &ListNode{}
+	tail := dummy
+	for _, val := range ParseValues(s) {
+		if val == nil {
+			continue
+		}
+
+		tail.Next = &ListNode{Val: *val}
+		tail = tail.Next
+	}
+
+	return dummy.Next
+}
+
+// BuildTree builds a binary tree out of LeetCode's array notation, e.g. `[1,null,2,3]`.
+func BuildTree(s string) *TreeNode {
+	values := ParseValues(s)
+	if len(values) == 0 || values[0] == nil {
+		return nil
+	}
+
+	root := &TreeNode{Val: *values[0]}
+	queue := []*TreeNode{root}
+	i := 1
+
+	for len(queue) > 0 && i < len(values) {
+		node := queue[0]
+		queue = queue[1:]
+
+		for _, child := range []**TreeNode{&node.Left, &node.Right} {
+			if i >= len(values) {
+				break
+			}
+
+			if values[i] != nil {
+				*child = &TreeNode{Val: *values[i]}
+				queue = append(queue, *child)
+			}
+			i++
+		}
+	}
+
+	return root
+}
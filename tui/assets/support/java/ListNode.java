@@ -0,0 +1,45 @@
+// Local definition of the singly-linked list LeetCode provides to Java solutions.
+//
+// Generated by leetui, this file is never submitted.
+
+import java.util.ArrayList;
+import java.util.List;
+
+public class ListNode {
+    int val;
+    ListNode next;
+
+    ListNode() {}
+
+    ListNode(int val) {
+        this.val = val;
+    }
+
+    ListNode(int val, ListNode next) {
+        this.val = val;
+        this.next = next;
+    }
+
+    /** Builds a linked list out of LeetCode's array notation, e.g. `[1,2,3]`. */
+    public static ListNode of(String s) {
+        ListNode dummy = new ListNode();
+        ListNode tail = dummy;
+        for (Integer val : TreeNode.parseValues(s)) {
+            if (val == null) continue;
+            tail.next = new ListNode(val);
+            tail = tail.next;
+        }
+
+        return dummy.next;
+    }
+
+    @Override
+    public String toString() {
+        List<String> values = new ArrayList<>();
+        for (ListNode node = this; node != null; node = node.next) {
+            values.add(String.valueOf(node.val));
+        }
+
+        return "[" + String.join(",", values) + "]";
+    }
+}
@@ -0,0 +1,94 @@
+// Local definition of the binary tree node LeetCode provides to Java solutions.
+//
+// Generated by leetui, this file is never submitted.
+
+import java.util.ArrayDeque;
+import java.util.ArrayList;
+import java.util.LinkedList;
+import java.util.List;
+import java.util.Queue;
+
+public class TreeNode {
+    int val;
+    TreeNode left;
+    TreeNode right;
+
+    TreeNode() {}
+
+    TreeNode(int val) {
+        this.val = val;
+    }
+
+    TreeNode(int val, TreeNode left, TreeNode right) {
+        this.val = val;
+        this.left = left;
+        this.right = right;
+    }
+
+    /** Parses LeetCode's array notation, e.g. `[3,9,20,null,null,15,7]`. */
+    static List<Integer> parseValues(String s) {
+        List<Integer> values = new ArrayList<>();
+        String inner = s.trim().replaceAll("^\\[|\\]$", "");
+        for (String token : inner.split(",")) {
+            token = token.trim();
+            if (token.isEmpty()) continue;
+            values.add(token.equals("null") ? null : Integer.parseInt(token));
+        }
+
+        return values;
+    }
+
+    /** Builds a binary tree out of LeetCode's array notation, e.g. `[1,null,2,3]`. */
+    public static TreeNode of(String s) {
+        List<Integer> values = parseValues(s);
+        if (values.isEmpty() || values.get(0) == null) return null;
+
+        TreeNode root = new TreeNode(values.get(0));
+        Queue<TreeNode> queue = new ArrayDeque<>();
+        queue.add(root);
+
+        int i = 1;
+        while (!queue.isEmpty() && i < values.size()) {
+            TreeNode node = queue.poll();
+
+            if (i < values.size() && values.get(i) != null) {
+                node.left = new TreeNode(values.get(i));
+                queue.add(node.left);
+            }
+            i++;
+
+            if (i < values.size() && values.get(i) != null) {
+                node.right = new TreeNode(values.get(i));
+                queue.add(node.right);
+            }
+            i++;
+        }
+
+        return root;
+    }
+
+    @Override
+    public String toString() {
+        List<String> values = new ArrayList<>();
+        Queue<TreeNode> queue = new LinkedList<>();
+        queue.add(this);
+
+        while (!queue.isEmpty()) {
+            TreeNode node = queue.poll();
+            if (node == null) {
+                values.add("null");
+                continue;
+            }
+
+            values.add(String.valueOf(node.val));
+            queue.add(node.left);
+            queue.add(node.right);
+        }
+
+        while (!values.isEmpty() && values.get(values.size() - 1).equals("null")) {
+            values.remove(values.size() - 1);
+        }
+
+        return "[" + String.join(",", values) + "]";
+    }
+}
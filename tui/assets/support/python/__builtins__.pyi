@@ -0,0 +1,22 @@
+# Names LeetCode makes available to Python solutions without imports.
+#
+# Generated by leetui so language servers can resolve them.
+
+import bisect as bisect
+import collections as collections
+import heapq as heapq
+import itertools as itertools
+import math as math
+from collections import Counter as Counter
+from collections import defaultdict as defaultdict
+from collections import deque as deque
+from functools import cache as cache
+from functools import lru_cache as lru_cache
+from typing import Dict as Dict
+from typing import List as List
+from typing import Optional as Optional
+from typing import Set as Set
+from typing import Tuple as Tuple
+
+from leetcode import ListNode as ListNode
+from leetcode import TreeNode as TreeNode
@@ -0,0 +1,102 @@
+"""Local definitions of the data structures LeetCode provides to Python solutions.
+
+Generated by leetui, this file is never submitted.
+"""
+
+from collections import deque
+from typing import List, Optional
+
+
+class ListNode:
+    def __init__(self, val=0, next=None):
+        self.val = val
+        self.next = next
+
+    def __repr__(self):
+        return f"ListNode({list_to_array(self)})"
+
+
+class TreeNode:
+    def __init__(self, val=0, left=None, right=None):
+        self.val = val
+        self.left = left
+        self.right = right
+
+    def __repr__(self):
+        return f"TreeNode({tree_to_array(self)})"
+
+
+def parse_values(s: str) -> List[Optional[int]]:
+    """Parses LeetCode's array notation, e.g. `[3,9,20,null,null,15,7]`."""
+    inner = s.strip().lstrip("[").rstrip("]")
+    values = [v.strip() for v in inner.split(",") if v.strip()]
+    return [None if v == "null" else int(v) for v in values]
+
+
+def build_list(values) -> Optional[ListNode]:
+    """Builds a linked list out of a list of values or LeetCode's array notation."""
+    if isinstance(values, str):
+        values = [v for v in parse_values(values) if v is not None]
+
+    head = None
+    for val in reversed(values):
+        head = ListNode(val, head)
+
+    return head
+
+
+def list_to_array(head: Optional[ListNode]) -> List[int]:
+    """Collects the values of a linked list."""
+    values = []
+    while head:
+        values.append(head.val)
+        head = head.next
+
+    return values
+
+
+def build_tree(values) -> Optional[TreeNode]:
+    """Builds a binary tree out of its level order values or LeetCode's array notation."""
+    if isinstance(values, str):
+        values = parse_values(values)
+
+    if not values or values[0] is None:
+        return None
+
+    it = iter(values)
+    root = TreeNode(next(it))
+    queue = deque([root])
+
+    while queue:
+        node = queue.popleft()
+        for side in ("left", "right"):
+            val = next(it, StopIteration)
+            if val is StopIteration:
+                return root
+            if val is not None:
+                child = TreeNode(val)
+                setattr(node, side, child)
+                queue.append(child)
+
+    return root
+
+
+def tree_to_array(root: Optional[TreeNode]) -> List[Optional[int]]:
+    """Collects the level order values of a binary tree, without trailing nulls."""
+    values = []
+    queue = deque([root])
+
+    while queue:
+        node = queue.popleft()
+        if node is None:
+            values.append(None)
+            continue
+
+        values.append(node.val)
+        queue.append(node.left)
+        queue.append(node.right)
+
+    while values and values[-1] is None:
+        values.pop()
+
+    return values
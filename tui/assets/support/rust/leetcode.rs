@@ -0,0 +1,133 @@
+//! Local definitions of the data structures LeetCode provides to Rust solutions.
+//!
+//! Generated by leetui, this file is never submitted.
+#![allow(dead_code)]
+
+pub use std::cell::RefCell;
+pub use std::rc::Rc;
+
+/// Definition for singly-linked list.
+#[derive(PartialEq, Eq, Clone, Debug)]
+pub struct ListNode {
+    pub val: i32,
+    pub next: Option<Box<ListNode>>,
+}
+
+impl ListNode {
+    #[inline]
+    pub fn new(val: i32) -> Self {
+        ListNode { next: None, val }
+    }
+}
+
+/// Definition for a binary tree node.
+#[derive(Debug, PartialEq, Eq)]
+pub struct TreeNode {
+    pub val: i32,
+    pub left: Option<Rc<RefCell<TreeNode>>>,
+    pub right: Option<Rc<RefCell<TreeNode>>>,
+}
+
+impl TreeNode {
+    #[inline]
+    pub fn new(val: i32) -> Self {
+        TreeNode {
+            val,
+            left: None,
+            right: None,
+        }
+    }
+}
+
+/// Parses LeetCode's array notation, e.g. `[3,9,20,null,null,15,7]`.
+pub fn parse_values(s: &str) -> Vec<Option<i32>> {
+    s.trim()
+        .trim_start_matches('[')
+        .trim_end_matches(']')
+        .split(',')
+        .map(str::trim)
+        .filter(|v| !v.is_empty())
+        .map(|v| v.parse().ok())
+        .collect()
+}
+
+/// Builds a linked list out of its values.
+pub fn list_from_vec(values: &[i32]) -> Option<Box<ListNode>> {
+    let mut head = None;
+    for &val in values.iter().rev() {
+        head = Some(Box::new(ListNode { val, next: head }));
+    }
+
+    head
+}
+
+/// Builds a linked list out of LeetCode's array notation, e.g. `[1,2,3]`.
+pub fn list(s: &str) -> Option<Box<ListNode>> {
+    let values: Vec<_> = parse_values(s).into_iter().flatten().collect();
+    list_from_vec(&values)
+}
+
+/// Collects the values of a linked list.
+pub fn list_to_vec(mut head: &Option<Box<ListNode>>) -> Vec<i32> {
+    let mut values = Vec::new();
+    while let Some(node) = head {
+        values.push(node.val);
+        head = &node.next;
+    }
+
+    values
+}
+
+/// Builds a binary tree out of its level order values.
+pub fn tree_from_vec(values: &[Option<i32>]) -> Option<Rc<RefCell<TreeNode>>> {
+    let mut values = values.iter();
+    let root = Rc::new(RefCell::new(TreeNode::new((*values.next()?)?)));
+
+    let mut queue = std::collections::VecDeque::from([root.clone()]);
+    while let Some(node) = queue.pop_front() {
+        let node = &mut *node.borrow_mut();
+
+        for child in [&mut node.left, &mut node.right] {
+            match values.next() {
+                Some(Some(val)) => {
+                    let new = Rc::new(RefCell::new(TreeNode::new(*val)));
+                    queue.push_back(new.clone());
+                    *child = Some(new);
+                }
+                Some(None) => {}
+                None => return Some(root),
+            }
+        }
+    }
+
+    Some(root)
+}
+
+/// Builds a binary tree out of LeetCode's array notation, e.g. `[1,null,2,3]`.
+pub fn tree(s: &str) -> Option<Rc<RefCell<TreeNode>>> {
+    tree_from_vec(&parse_values(s))
+}
+
+/// Collects the level order values of a binary tree, without trailing nulls.
+pub fn tree_to_vec(root: &Option<Rc<RefCell<TreeNode>>>) -> Vec<Option<i32>> {
+    let mut values = Vec::new();
+    let mut queue = std::collections::VecDeque::from([root.clone()]);
+
+    while let Some(node) = queue.pop_front() {
+        match node {
+            Some(node) => {
+                let node = node.borrow();
+                values.push(Some(node.val));
+                queue.push_back(node.left.clone());
+                queue.push_back(node.right.clone());
+            }
+            None => values.push(None),
+        }
+    }
+
+    while values.last() == Some(&None) {
+        values.pop();
+    }
+
+    values
+}
@@ -1,35 +1,78 @@
 use std::{
-    collections::HashSet,
+    cmp::Ordering,
+    collections::{BTreeMap, HashMap, HashSet},
     env, fs, io,
     os::unix::process::{CommandExt, ExitStatusExt},
-    path::PathBuf,
-    process::{Child, Command, ExitStatus},
+    path::{Path, PathBuf},
+    process::{self, Child, ExitStatus, Stdio},
+    thread,
+    time::{Duration, Instant},
 };
 
-use api::{Language, MatchedUser, ProblemSummary, Question, UserStatus};
+use api::{
+    Difficulty, Document, Language, MatchedUser, ProblemSummary, Question, StatusMsg,
+    SubmissionCheckResponse, TestCasesCheckResponse, UserStatus,
+};
+use image::DynamicImage;
 use ratatui::{
     Frame,
-    crossterm::event::{self, KeyCode, KeyEvent, KeyModifiers},
-    layout::{Constraint, Direction, Layout},
-    style::{Color, Style},
-    widgets::{Paragraph, TableState},
+    crossterm::event::{
+        self, KeyCode, KeyEvent, KeyModifiers, MouseButton, MouseEvent, MouseEventKind,
+    },
+    layout::{Constraint, Direction, Layout, Position, Rect},
+    text::Line,
+    widgets::TableState,
 };
 use tokio::sync::mpsc::Sender;
 
-use super::{handler::ClientRequest, rendering, utils};
+use super::{
+    code_editor::{CodeEditor, EditorCommand},
+    config::Config,
+    external_editor::{self, EditorPane, ExternalEditor, Multiplexer},
+    handler::ClientRequest,
+    images::{self, Images},
+    keymap::{
+        self, Command, FieldKey, HELP_KEY, KeyBinding, Keymap, Lookup, SEARCH_KEYS,
+        SOLUTION_NAME_KEYS, Scope, TEST_CASE_FIELD_KEYS, TextInput,
+    },
+    palette::{Palette, PaletteAction, PaletteEntry},
+    rendering,
+    theme::{self, THEMES, Theme},
+    watcher::FileWatcher,
+    workspace,
+};
+
+/// The smallest terminal the interface is drawn in.
+const MIN_WIDTH: u16 = 60;
+const MIN_HEIGHT: u16 = 16;
+
+/// The width from which the test cases and solution go next to the
+/// description rather than below it.
+const SIDE_BY_SIDE_WIDTH: u16 = 150;
+
+/// How long a suspended editor has to exit after being hung up before it's killed.
+const HANGUP_GRACE: Duration = Duration::from_millis(500);
 
 /// The types of events that exist in both apps.
 #[derive(Debug)]
 pub enum Action {
     Key(event::KeyEvent),
+    Mouse(event::MouseEvent),
+    Resize,
 
+    /// Advances the spinner while something is loading.
     Tick,
+    /// Checks whether the editor next to leetui closed or wrote the solution.
+    Poll,
 
     UserStatusLoaded(UserStatus),
     UserProfileLoaded(MatchedUser),
     ProblemListLoaded(Vec<ProblemSummary>),
     DailyChallengeLoaded(ProblemSummary),
     QuestionLoaded(Question),
+    TestsChecked(TestCasesCheckResponse),
+    SubmissionChecked(SubmissionCheckResponse),
+    ImageLoaded(String, Option<DynamicImage>),
 
     NetworkError(String),
     Other,
@@ -45,6 +88,10 @@ pub enum UpdateResult {
 pub enum HomeInputState {
     Normal,
     Searching,
+    /// Listing the suspended editors, either on request or before quitting.
+    Sessions {
+        exiting: bool,
+    },
 }
 
 #[derive(Clone, Copy)]
@@ -53,6 +100,9 @@ pub enum EditorState {
     Description,
     TestCases,
     EditingTestCaseField,
+    Coding,
+    SelectingSolution,
+    NamingSolution,
 }
 
 enum AppState {
@@ -60,12 +110,106 @@ enum AppState {
     Editor,
 }
 
+/// What is drawn at some area of the screen, for the mouse to act on.
+#[derive(Debug, Clone, Copy)]
+pub enum MouseTarget {
+    /// A help bar entry, which runs its command when clicked.
+    Command(Command),
+    Problem(usize),
+    ProblemList,
+    Daily,
+    Description,
+    Preview,
+    TestCase(usize),
+    CaseField(usize),
+    Language(usize),
+    PaletteEntry(usize),
+}
+
+#[derive(Clone)]
 pub struct TestCase {
     pub input: Vec<String>,
     pub output: Option<String>,
     pub expected: Option<String>,
 }
 
+impl TestCase {
+    /// Checks the output of the last run against the expected one.
+    ///
+    /// # Returns
+    /// Whether the case passed, or `None` if either output is unknown.
+    pub fn passed(&self) -> Option<bool> {
+        let output = self.output.as_deref()?;
+        let expected = self.expected.as_deref()?;
+        Some(normalize_output(output) == normalize_output(expected))
+    }
+}
+
+/// Finds what a key does in a text field, if it doesn't type a character.
+///
+/// # Arguments
+/// * `table` - The keys of the field.
+/// * `key` - The key pressed.
+fn field_key(table: &[keymap::KeyBindings<FieldKey>], key: KeyEvent) -> Option<FieldKey> {
+    match keymap::lookup(table, None, KeyBinding::from_event(key)) {
+        Lookup::Action(action) => Some(action),
+        _ => None,
+    }
+}
+
+/// Removes the whitespace outside of string literals so outputs can be compared.
+fn normalize_output(output: &str) -> String {
+    let mut normalized = String::with_capacity(output.len());
+    let mut in_string = false;
+    let mut escaped = false;
+
+    for ch in output.chars() {
+        match ch {
+            '"' if !escaped => in_string = !in_string,
+            _ if ch.is_whitespace() && !in_string => continue,
+            _ => {}
+        }
+
+        escaped = ch == '\\' && !escaped;
+        normalized.push(ch);
+    }
+
+    normalized
+}
+
+/// The outcome of the last run or submission.
+pub struct RunStatus {
+    pub title: String,
+    pub accepted: bool,
+    pub details: Option<String>,
+}
+
+/// The code of the solution for the selected language, ready to be shown.
+pub struct SolutionPreview {
+    /// Where the code comes from, like `solution.rs`.
+    pub source: String,
+    pub lines: Vec<Line<'static>>,
+}
+
+/// A terminal editor left suspended while its problem was closed.
+pub struct EditorSession {
+    pub child: Child,
+    pub lang: Language,
+}
+
+/// A closed editor that has until its deadline to exit after being hung up.
+struct HungUpEditor {
+    child: Child,
+    deadline: Instant,
+}
+
+/// A read-only field shown below the inputs of a test case.
+pub struct ResultField<'a> {
+    pub label: &'static str,
+    pub value: &'a str,
+    pub passed: Option<bool>,
+}
+
 pub struct App {
     // Main Fields
     pub error_message: Option<String>,
@@ -82,6 +226,8 @@ pub struct App {
 
     // Search Bar
     pub search_bar_input: String,
+    /// The only difficulty the problem list shows, if any.
+    pub difficulty_filter: Option<Difficulty>,
     pub home_input_state: HomeInputState,
 
     // Problem List
@@ -97,21 +243,55 @@ pub struct App {
 
     // language selector
     pub selected_language: Option<Language>,
-    pub solution_paths: Vec<PathBuf>,
     pub language_selection_index: usize,
 
+    // solution picker
+    pub solution_paths: Vec<PathBuf>,
+    pub selected_solution: Option<PathBuf>,
+    pub solution_selection_index: usize,
+    pub new_solution_name: String,
+
     // description
+    pub description: Document,
     pub description_offset: usize,
+    pub images: Images,
+    pub visible_images: Vec<String>,
+
+    // solution preview
+    pub show_preview: bool,
+    pub preview: Option<SolutionPreview>,
+    pub preview_offset: usize,
 
     // test cases
     pub test_cases: Vec<TestCase>,
+    pub default_test_cases: Vec<TestCase>,
     pub selected_test_case: usize,
     pub selected_case_text: usize,
     pub test_cases_scroll_offset: usize,
     pub last_test_case_viewport_height: u16,
+    pub run_status: Option<RunStatus>,
 
     // editor
-    pub editor_subprocess: Option<Child>,
+    pub editor_sessions: BTreeMap<String, EditorSession>,
+    pub session_index: usize,
+    resume_session: Option<String>,
+    hung_up_editors: Vec<HungUpEditor>,
+    /// The editors opened in a pane next to leetui, by problem.
+    editor_panes: HashMap<String, EditorPane>,
+    pub code_editor: Option<CodeEditor>,
+    pub gui_editor: Option<Child>,
+    solution_watcher: Option<FileWatcher>,
+
+    pub config: Config,
+    pub theme: Theme,
+    pub keymap: Keymap,
+    pub palette: Option<Palette>,
+    pub show_help: bool,
+    pub help_offset: usize,
+    /// The areas the mouse can act on, from the last frame drawn.
+    pub mouse_targets: Vec<(Rect, MouseTarget)>,
+    /// Whether the last frame was too small to draw anything but a notice.
+    too_small: bool,
 }
 
 impl App {
@@ -119,10 +299,16 @@ impl App {
     ///
     /// # Arguments
     /// * `client_tx` - A sender to tell the client handler to make a request to the LeetCode api.
+    /// * `images` - The store for the images of descriptions.
+    /// * `config` - The user's settings.
     ///
     /// # Returns
     /// A new instance of `Self`.
-    pub fn new(client_tx: Sender<ClientRequest>) -> Self {
+    pub fn new(client_tx: Sender<ClientRequest>, images: Images, config: Config) -> Self {
+        workspace::set_root_dir(config.workspace_dir.clone());
+        let theme = Theme::from_config(&config.theme).unwrap_or_default();
+        let keymap = Keymap::from_config(&config.keys).unwrap_or_default();
+
         let app = Self {
             problems: Vec::new(),
             problem_table_state: TableState::default().with_selected(0),
@@ -133,6 +319,7 @@ impl App {
             error_message: None,
             client_tx,
             search_bar_input: String::new(),
+            difficulty_filter: None,
             home_input_state: HomeInputState::Normal,
             known_ids: HashSet::new(),
             has_more: true,
@@ -141,32 +328,71 @@ impl App {
             question: None,
             selected_language: None,
             solution_paths: Vec::new(),
+            selected_solution: None,
+            solution_selection_index: 0,
+            new_solution_name: String::new(),
+            description: Document::default(),
             description_offset: 0,
+            images,
+            visible_images: Vec::new(),
+            show_preview: false,
+            preview: None,
+            preview_offset: 0,
             test_cases: Vec::new(),
+            default_test_cases: Vec::new(),
             selected_test_case: 0,
             selected_case_text: 0,
             editor_state: EditorState::Description,
             test_cases_scroll_offset: 0,
             last_test_case_viewport_height: 0,
+            run_status: None,
             language_selection_index: 0,
-            editor_subprocess: None,
+            editor_sessions: BTreeMap::new(),
+            session_index: 0,
+            resume_session: None,
+            hung_up_editors: Vec::new(),
+            editor_panes: HashMap::new(),
+            code_editor: None,
+            gui_editor: None,
+            solution_watcher: None,
+            config,
+            theme,
+            keymap,
+            palette: None,
+            show_help: false,
+            help_offset: 0,
+            mouse_targets: Vec::new(),
+            too_small: false,
         };
 
         app.send_request(ClientRequest::FetchUserStatus);
         app.send_request(ClientRequest::FetchDailyChallenge);
         app.send_request(ClientRequest::FetchProblems {
             skip: 0,
-            limit: 50,
+            limit: app.config.page_size,
             search: None,
+            difficulty: None,
         });
         app
     }
 
     pub fn render(&mut self, frame: &mut Frame) {
+        self.mouse_targets.clear();
+
+        let area = frame.area();
+        self.too_small = area.width < MIN_WIDTH || area.height < MIN_HEIGHT;
+        if self.too_small {
+            rendering::too_small(frame, area, (MIN_WIDTH, MIN_HEIGHT), &self.theme);
+            return;
+        }
+
         match self.state {
             AppState::Home => self.render_home(frame),
             AppState::Editor => self.render_editor(frame),
         }
+
+        rendering::help_overlay(frame, frame.area(), self);
+        rendering::command_palette(frame, frame.area(), self);
     }
 
     fn render_home(&mut self, frame: &mut Frame) {
@@ -200,12 +426,7 @@ impl App {
         rendering::daily_challenge(frame, main_chunks[5], self);
         rendering::problem_list(frame, main_chunks[7], self);
         rendering::home_controls(frame, main_chunks[9], self);
-
-        if let Some(ref err) = self.error_message {
-            let err_line = Paragraph::new(format!(" ERROR: {}", err))
-                .style(Style::default().fg(Color::Red).bg(Color::Black));
-            frame.render_widget(err_line, main_chunks[9]);
-        }
+        rendering::editor_sessions(frame, outer_layout[1], self);
     }
 
     pub fn render_editor(&mut self, frame: &mut Frame) {
@@ -218,53 +439,168 @@ impl App {
             ])
             .split(frame.area());
 
-        let constraints: &[_] = match self.editor_state {
+        let pane = match self.editor_state {
             EditorState::SelectingLanguage => {
                 let rows =
                     (self.question.as_ref().unwrap().code_snippets.len() as f32 / 3.0).ceil();
 
-                &[
-                    Constraint::Length(1),               // padding
-                    Constraint::Min(0),                  // description
-                    Constraint::Length(3 + rows as u16), // test cases + language selector + language grid
-                    Constraint::Length(1),               // padding
-                    Constraint::Length(1),               // controls
-                ]
+                Constraint::Length(3 + rows as u16) // test cases + language selector + language grid
+            }
+            EditorState::SelectingSolution | EditorState::NamingSolution => {
+                let rows = self.solution_paths.len().max(1) + 1;
+
+                Constraint::Length(3 + rows as u16) // test cases + language selector + solutions
+            }
+            EditorState::Description | EditorState::Coding => {
+                Constraint::Length(3) // test cases + language selector
             }
-            EditorState::Description => &[
-                Constraint::Length(1), // padding
-                Constraint::Min(0),    // description
-                Constraint::Length(3), // test cases + language selector
-                Constraint::Length(1), // padding
-                Constraint::Length(1), // controls
-            ],
             _ => {
-                let inputs = self.question.as_ref().unwrap().meta_data.params.len();
-                let exact_size = 2 + 5 * (inputs);
+                let fields = self.test_case_field_count();
+                let exact_size = 2 + 5 * fields;
 
-                &[
-                    Constraint::Length(1),              // padding
-                    Constraint::Min(0),                 // description
-                    Constraint::Max(exact_size as u16), // test cases + language selector
-                    Constraint::Length(1),              // padding
-                    Constraint::Length(1),              // controls
-                ]
+                Constraint::Max(exact_size as u16) // test cases + language selector
             }
         };
 
         let main_chunks = Layout::default()
             .direction(Direction::Vertical)
-            .constraints(constraints)
+            .constraints([
+                Constraint::Length(1), // padding
+                Constraint::Min(0),    // description, solution and test cases
+                Constraint::Length(1), // padding
+                Constraint::Length(1), // controls
+            ])
             .split(outer_layout[1]);
 
-        rendering::description(frame, main_chunks[1], self);
-        rendering::test_cases_languages_pane(frame, main_chunks[2], self);
-        rendering::editor_controls(frame, main_chunks[4], self);
+        let has_side_pane = self.code_editor.is_some() || self.show_preview;
+
+        if main_chunks[1].width >= SIDE_BY_SIDE_WIDTH {
+            let columns = Layout::default()
+                .direction(Direction::Horizontal)
+                .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
+                .split(main_chunks[1]);
+
+            // With nothing above them, the test cases get the whole column.
+            let right_constraints = match has_side_pane {
+                true => [Constraint::Min(0), pane],
+                false => [Constraint::Length(0), Constraint::Min(0)],
+            };
+
+            let right = Layout::default()
+                .direction(Direction::Vertical)
+                .constraints(right_constraints)
+                .split(columns[1]);
+
+            rendering::description(frame, columns[0], self);
+            if has_side_pane {
+                self.render_side_pane(frame, right[0]);
+            }
+            rendering::test_cases_languages_pane(frame, right[1], self);
+        } else {
+            let rows = Layout::default()
+                .direction(Direction::Vertical)
+                .constraints([Constraint::Min(0), pane])
+                .split(main_chunks[1]);
+
+            match has_side_pane {
+                false => rendering::description(frame, rows[0], self),
+                true => {
+                    let panes = Layout::default()
+                        .direction(Direction::Horizontal)
+                        .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
+                        .split(rows[0]);
+
+                    rendering::description(frame, panes[0], self);
+                    self.render_side_pane(frame, panes[1]);
+                }
+            }
+            rendering::test_cases_languages_pane(frame, rows[1], self);
+        }
+
+        rendering::editor_controls(frame, main_chunks[3], self);
+    }
+
+    /// Renders the built-in editor next to the description, or the preview
+    /// of the solution if the editor isn't open.
+    fn render_side_pane(&mut self, frame: &mut Frame, rect: Rect) {
+        match self.code_editor.is_some() {
+            true => rendering::code_editor(frame, rect, self),
+            false => rendering::solution_preview(frame, rect, self),
+        }
     }
 
     pub fn update(&mut self, action: Action) -> UpdateResult {
+        // Only the notice is drawn, so input that would act unseen is ignored.
+        if self.too_small {
+            match action {
+                Action::Mouse(_) => return UpdateResult::SkipRendering,
+                Action::Key(key) => {
+                    let command = self
+                        .key_scope()
+                        .and_then(|scope| self.keymap.command(scope, key));
+
+                    return match command {
+                        Some(Command::Quit) => self.run_command(Command::Quit),
+                        _ => UpdateResult::SkipRendering,
+                    };
+                }
+                _ => {}
+            }
+        }
+
+        // Editors outlive the editor view, so they're polled from any state,
+        // without clearing an error the user hasn't seen yet.
+        if let Action::Poll = action {
+            self.poll_hung_up_editors();
+            let editor_closed = self.poll_gui_editor();
+            let solution_changed = self.poll_solution();
+
+            return match editor_closed || solution_changed {
+                true => UpdateResult::Continue,
+                false => UpdateResult::SkipRendering,
+            };
+        }
+
         self.error_message = None;
 
+        if let Action::ImageLoaded(url, image) = action {
+            self.images.finish_loading(url, image);
+            return UpdateResult::Continue;
+        }
+
+        if let Action::Mouse(mouse) = action {
+            return self.handle_mouse(mouse);
+        }
+
+        // Every layout is computed from the frame, so a redraw is all it takes.
+        if let Action::Resize = action {
+            return UpdateResult::Continue;
+        }
+
+        if let Action::Key(key) = action {
+            if self.palette.is_some() {
+                return self.handle_palette_key(key);
+            }
+
+            if self.show_help {
+                self.handle_help_key(key);
+                return UpdateResult::Continue;
+            }
+
+            if KeyBinding::from_event(key) == HELP_KEY && self.text_input().is_some() {
+                self.show_help = true;
+                self.help_offset = 0;
+                return UpdateResult::Continue;
+            }
+
+            if let Some(scope) = self.key_scope() {
+                return match self.keymap.command(scope, key) {
+                    Some(command) => self.run_command(command),
+                    None => UpdateResult::Continue,
+                };
+            }
+        }
+
         match self.state {
             AppState::Home => self.update_home(action),
             AppState::Editor => self.update_editor(action),
@@ -273,12 +609,13 @@ impl App {
 
     fn update_home(&mut self, action: Action) -> UpdateResult {
         match action {
-            Action::Key(key) => match self.home_input_state {
-                HomeInputState::Normal => return self.handle_home_normal_key(key),
-                HomeInputState::Searching => return self.handle_home_searching_key(key),
-            },
+            Action::Key(key) => {
+                if let HomeInputState::Searching = self.home_input_state {
+                    return self.handle_home_searching_key(key);
+                }
+            }
             Action::ProblemListLoaded(problems) => {
-                self.has_more = problems.len() >= 50;
+                self.has_more = problems.len() >= self.config.page_size;
 
                 for p in problems {
                     if !self.known_ids.contains(&p.frontend_question_id) {
@@ -293,30 +630,73 @@ impl App {
 
                 self.is_loading = false;
             }
-            Action::QuestionLoaded(mut question) => {
+            Action::QuestionLoaded(question) => {
                 if let Err(e) = self.load_local_file_paths(&question) {
                     self.error_message = Some(e.to_string());
                     return UpdateResult::Continue;
                 }
 
-                question.content = utils::html_to_markdown(&question.content);
+                self.description = question.document();
+                self.description_offset = 0;
+                self.fetch_images();
 
                 let param_count = question.meta_data.params.len();
                 let lines: Vec<_> = question.example_testcases.lines().collect();
+                let inputs: Vec<_> = lines.chunks(param_count).collect();
+
+                // Outputs are matched to inputs by position, which only holds
+                // when every example has one.
+                let outputs: Vec<_> = self
+                    .description
+                    .examples()
+                    .map(|example| example.output.clone())
+                    .collect();
+                let aligned = outputs.len() == inputs.len();
 
-                self.test_cases = lines
-                    .chunks(param_count)
-                    .map(|chunk| TestCase {
+                self.default_test_cases = inputs
+                    .iter()
+                    .enumerate()
+                    .map(|(i, chunk)| TestCase {
                         input: chunk.iter().map(|s| s.to_string()).collect(),
                         output: None,
-                        expected: None,
+                        expected: outputs.get(i).filter(|_| aligned).cloned(),
                     })
                     .collect();
 
+                let dir_path = workspace::problem_dir(&question.title_slug);
+                self.test_cases = match workspace::load_test_cases(&dir_path, param_count) {
+                    Ok(Some(cases)) => cases,
+                    Ok(None) => self.default_test_cases.clone(),
+                    Err(e) => {
+                        self.error_message = Some(e.to_string());
+                        self.default_test_cases.clone()
+                    }
+                };
+
                 self.is_loading = false;
                 self.selected_test_case = 0;
+                self.run_status = None;
+                let slug = question.title_slug.clone();
+                self.selected_solution = None;
                 self.question = Some(question);
                 self.state = AppState::Editor;
+                self.editor_state = EditorState::Description;
+
+                if let Some(session) = self.editor_sessions.get(&slug) {
+                    self.selected_language = Some(session.lang);
+                } else if self.selected_language.is_none() {
+                    let snippets = &self.question.as_ref().unwrap().code_snippets;
+                    self.selected_language = self
+                        .config
+                        .default_language
+                        .filter(|lang| snippets.iter().any(|cs| cs.lang == *lang));
+                }
+
+                self.reload_preview();
+
+                if self.resume_session.take() == Some(slug) {
+                    return UpdateResult::OpenEditor;
+                }
             }
             Action::Tick => {
                 if !self.is_loading {
@@ -351,15 +731,28 @@ impl App {
     fn update_editor(&mut self, action: Action) -> UpdateResult {
         match action {
             Action::Key(key_event) => match self.editor_state {
-                EditorState::SelectingLanguage => {
-                    self.handle_editor_selecting_language_key(key_event)
-                }
-                EditorState::Description => return self.handle_editor_description_key(key_event),
-                EditorState::TestCases => self.handle_editor_test_cases_key(key_event),
                 EditorState::EditingTestCaseField => {
                     self.handle_editor_editing_test_case_key(key_event)
                 }
+                EditorState::Coding => self.handle_editor_coding_key(key_event),
+                EditorState::NamingSolution => self.handle_editor_naming_solution_key(key_event),
+                _ => {}
             },
+            Action::TestsChecked(res) => {
+                self.is_loading = false;
+                self.handle_tests_checked(res);
+                self.save_test_cases();
+                self.editor_state = EditorState::TestCases;
+            }
+            Action::SubmissionChecked(res) => {
+                self.is_loading = false;
+                self.handle_submission_checked(res);
+                self.editor_state = EditorState::TestCases;
+            }
+            Action::NetworkError(e) => {
+                self.is_loading = false;
+                self.error_message = Some(e);
+            }
             Action::Tick => {
                 if !self.is_loading {
                     return UpdateResult::SkipRendering;
@@ -373,22 +766,57 @@ impl App {
         UpdateResult::Continue
     }
 
-    /// Handles an incoming key event for the normal mode.
+    /// Runs a command of the current scope, whether its key was pressed or
+    /// it was picked in the command palette.
     ///
     /// # Arguments
-    /// * `key` - The incoming key event.
+    /// * `command` - The command to run.
+    ///
+    /// # Returns
+    /// The result of an update.
+    fn run_command(&mut self, command: Command) -> UpdateResult {
+        match command {
+            Command::Palette => {
+                self.open_palette();
+                return UpdateResult::Continue;
+            }
+            Command::Help => {
+                self.show_help = true;
+                self.help_offset = 0;
+                return UpdateResult::Continue;
+            }
+            _ => {}
+        }
+
+        match self.key_scope() {
+            Some(Scope::Home) => return self.handle_home_command(command),
+            Some(Scope::Sessions) => return self.handle_sessions_command(command),
+            Some(Scope::Description) => return self.handle_description_command(command),
+            Some(Scope::TestCases) => self.handle_test_cases_command(command),
+            Some(Scope::Languages) => self.handle_languages_command(command),
+            Some(Scope::Solutions) => self.handle_solutions_command(command),
+            None => {}
+        }
+
+        UpdateResult::Continue
+    }
+
+    /// Runs a command of the normal mode.
+    ///
+    /// # Arguments
+    /// * `command` - The command to run.
     ///
     /// # Returns
     /// The result of an update.
-    fn handle_home_normal_key(&mut self, key: KeyEvent) -> UpdateResult {
-        match (key.code, key.modifiers) {
-            (KeyCode::Char('j'), KeyModifiers::NONE) => self.scroll_down_problem_list(1),
-            (KeyCode::Char('k'), KeyModifiers::NONE) => self.scroll_up_problem_list(1),
-            (KeyCode::Char('/'), KeyModifiers::NONE) => {
+    fn handle_home_command(&mut self, command: Command) -> UpdateResult {
+        match command {
+            Command::Down => self.scroll_down_problem_list(1),
+            Command::Up => self.scroll_up_problem_list(1),
+            Command::Search => {
                 self.search_bar_input.clear();
                 self.home_input_state = HomeInputState::Searching;
             }
-            (KeyCode::Enter, _) => {
+            Command::Select => {
                 let Some(problem) = self
                     .problem_table_state
                     .selected()
@@ -398,13 +826,13 @@ impl App {
                 };
 
                 let user_is_premium = self.user_status.as_ref().unwrap().is_premium;
-                if !(problem.paid_only && !user_is_premium) {
+                if !problem.paid_only || user_is_premium {
                     let slug = problem.title_slug.to_string();
                     self.is_loading = true;
                     self.send_request(ClientRequest::FetchQuestion { slug });
                 }
             }
-            (KeyCode::Char('d'), KeyModifiers::NONE) => {
+            Command::Daily => {
                 let slug = self
                     .daily_challenge
                     .as_ref()
@@ -415,9 +843,17 @@ impl App {
                     self.send_request(ClientRequest::FetchQuestion { slug });
                 }
             }
-            (KeyCode::Char('d'), KeyModifiers::CONTROL) => self.scroll_down_problem_list(20),
-            (KeyCode::Char('u'), KeyModifiers::CONTROL) => self.scroll_up_problem_list(20),
-            (KeyCode::Esc, _) => return UpdateResult::Exit,
+            Command::PageDown => self.scroll_down_problem_list(20),
+            Command::PageUp => self.scroll_up_problem_list(20),
+            Command::Sessions => {
+                self.session_index = 0;
+                self.home_input_state = HomeInputState::Sessions { exiting: false };
+            }
+            Command::Quit if !self.editor_sessions.is_empty() => {
+                self.session_index = 0;
+                self.home_input_state = HomeInputState::Sessions { exiting: true };
+            }
+            Command::Quit => return UpdateResult::Exit,
             _ => {}
         };
 
@@ -429,168 +865,302 @@ impl App {
     /// # Arguments
     /// * `key` - The incoming key event.
     fn handle_home_searching_key(&mut self, key: KeyEvent) -> UpdateResult {
-        match key.code {
-            KeyCode::Char(ch) => {
-                self.search_bar_input.push(ch);
-            }
-            KeyCode::Backspace => {
+        match field_key(SEARCH_KEYS, key) {
+            Some(FieldKey::DeleteChar) => {
                 self.search_bar_input.pop();
             }
-            KeyCode::Esc => {
+            Some(FieldKey::Cancel) => {
                 self.home_input_state = HomeInputState::Normal;
             }
-            KeyCode::Enter => {
+            Some(FieldKey::Confirm) => {
                 self.home_input_state = HomeInputState::Normal;
-                self.problems.clear();
-                self.known_ids.clear();
-                self.is_loading = true;
-                self.has_more = true;
+                self.reload_problems();
+            }
+            None => {
+                if let KeyCode::Char(ch) = key.code {
+                    self.search_bar_input.push(ch);
+                }
+            }
+        }
 
-                self.send_request(ClientRequest::FetchProblems {
-                    skip: 0,
-                    limit: 50,
-                    search: (!self.search_bar_input.is_empty())
-                        .then_some(self.search_bar_input.clone()),
-                })
+        UpdateResult::Continue
+    }
+
+    /// Fetches the problem list again from the start, with the current
+    /// search and difficulty filter.
+    fn reload_problems(&mut self) {
+        self.problems.clear();
+        self.known_ids.clear();
+        self.is_loading = true;
+        self.has_more = true;
+
+        self.send_request(ClientRequest::FetchProblems {
+            skip: 0,
+            limit: self.config.page_size,
+            search: (!self.search_bar_input.is_empty()).then_some(self.search_bar_input.clone()),
+            difficulty: self.difficulty_filter,
+        });
+    }
+
+    /// Runs a command of the list of editor sessions.
+    ///
+    /// # Arguments
+    /// * `command` - The command to run.
+    fn handle_sessions_command(&mut self, command: Command) -> UpdateResult {
+        let count = self.editor_sessions.len();
+        let selected = self.editor_sessions.keys().nth(self.session_index).cloned();
+        let exiting = matches!(
+            self.home_input_state,
+            HomeInputState::Sessions { exiting: true }
+        );
+
+        match command {
+            Command::Back => self.home_input_state = HomeInputState::Normal,
+            Command::Down if self.session_index + 1 < count => self.session_index += 1,
+            Command::Up => self.session_index = self.session_index.saturating_sub(1),
+            Command::Select => {
+                if let Some(slug) = selected {
+                    self.home_input_state = HomeInputState::Normal;
+                    self.resume_session = Some(slug.clone());
+                    self.is_loading = true;
+                    self.send_request(ClientRequest::FetchQuestion { slug });
+                }
+            }
+            Command::Terminate => {
+                if let Some(slug) = selected {
+                    self.terminate_session(&slug);
+                    self.session_index = self.session_index.min(count.saturating_sub(2));
+                }
+            }
+            Command::TerminateAll if exiting => {
+                let slugs: Vec<_> = self.editor_sessions.keys().cloned().collect();
+                for slug in slugs {
+                    self.terminate_session(&slug);
+                }
+
+                return UpdateResult::Exit;
             }
             _ => {}
         }
 
+        if self.editor_sessions.is_empty() {
+            self.home_input_state = HomeInputState::Normal;
+        }
+
         UpdateResult::Continue
     }
 
-    fn handle_editor_selecting_language_key(&mut self, key: KeyEvent) {
+    /// Closes a suspended editor, hanging it up like a closed terminal would
+    /// so it gets a chance to keep its unsaved changes, e.g. in a swap file.
+    ///
+    /// The editor is killed on a later poll if it doesn't exit in time.
+    ///
+    /// # Arguments
+    /// * `slug` - The problem the editor was opened for.
+    fn terminate_session(&mut self, slug: &str) {
+        let Some(session) = self.editor_sessions.remove(slug) else {
+            return;
+        };
+
+        // The editor leads its own process group, so the whole group is signaled.
+        let pgid = session.child.id() as i32;
+        unsafe {
+            libc::kill(-pgid, libc::SIGHUP);
+            libc::kill(-pgid, libc::SIGCONT);
+        }
+
+        self.hung_up_editors.push(HungUpEditor {
+            child: session.child,
+            deadline: Instant::now() + HANGUP_GRACE,
+        });
+    }
+
+    /// Reaps the hung up editors that exited, killing the ones that ignored
+    /// the hangup, or stopped again, past their deadline.
+    fn poll_hung_up_editors(&mut self) {
+        let now = Instant::now();
+
+        self.hung_up_editors.retain_mut(|editor| {
+            if !matches!(editor.child.try_wait(), Ok(None)) {
+                return false;
+            }
+
+            if now >= editor.deadline {
+                unsafe { libc::kill(-(editor.child.id() as i32), libc::SIGKILL) };
+            }
+
+            true
+        });
+    }
+
+    /// Waits for the hung up editors before leetui exits, so none is left
+    /// running, killing the ones that are still alive past their deadline.
+    pub fn finish_hung_up_editors(&mut self) {
+        while !self.hung_up_editors.is_empty() {
+            self.poll_hung_up_editors();
+            thread::sleep(Duration::from_millis(10));
+        }
+    }
+
+    fn handle_languages_command(&mut self, command: Command) {
         let question = self.question.as_ref().unwrap();
         let snippets = &question.code_snippets;
         let nlangs = snippets.len();
         let rows = (nlangs as f32 / 3.0).ceil() as usize;
 
-        match key.code {
-            KeyCode::Esc | KeyCode::Char('c') => {
+        match command {
+            Command::Back => {
                 self.editor_state = EditorState::Description;
             }
-            KeyCode::Char('h') => {
-                if self.language_selection_index >= rows {
-                    self.language_selection_index -= rows;
-                }
+            Command::Left if self.language_selection_index >= rows => {
+                self.language_selection_index -= rows;
             }
-            KeyCode::Char('j') => {
-                if self.language_selection_index % rows < rows - 1 {
-                    self.language_selection_index =
-                        (self.language_selection_index + 1).min(nlangs - 1);
-                }
+            Command::Down if self.language_selection_index % rows < rows - 1 => {
+                self.language_selection_index = (self.language_selection_index + 1).min(nlangs - 1);
             }
-            KeyCode::Char('k') => {
-                if self.language_selection_index % rows > 0 {
-                    self.language_selection_index = self.language_selection_index.saturating_sub(1);
-                }
+            Command::Up if !self.language_selection_index.is_multiple_of(rows) => {
+                self.language_selection_index -= 1;
             }
-            KeyCode::Char('l') => {
-                if self.language_selection_index + rows < nlangs {
-                    self.language_selection_index += rows;
-                }
+            Command::Right if self.language_selection_index + rows < nlangs => {
+                self.language_selection_index += rows;
             }
-            KeyCode::Enter => {
+            Command::Select => {
                 let lang = snippets[self.language_selection_index].lang;
+                let dir_path = workspace::problem_dir(&question.title_slug);
+                let next = workspace::solution_path(&dir_path, lang.ext());
+                if let Err(e) = self.release_code_editor(&next) {
+                    self.error_message = Some(e.to_string());
+                    return;
+                }
+
                 self.selected_language = Some(lang);
+                self.selected_solution = None;
+                self.reload_preview();
             }
-            KeyCode::Char('t') => {
+            Command::TestCases => {
                 self.editor_state = EditorState::TestCases;
             }
             _ => {}
         }
     }
 
-    fn handle_editor_description_key(&mut self, key: KeyEvent) -> UpdateResult {
-        match key.code {
-            KeyCode::Esc => {
-                self.state = AppState::Home;
-
-                if let Some(mut child) = self.editor_subprocess.take() {
-                    if let Err(e) = child.kill() {
-                        self.error_message = Some(e.to_string());
-                    }
-                }
+    fn handle_description_command(&mut self, command: Command) -> UpdateResult {
+        match command {
+            Command::Back => {
+                self.leave_editor();
             }
-            KeyCode::Char('j') => {
+            Command::Down => {
                 self.description_offset = self.description_offset.saturating_add(1);
             }
-            KeyCode::Char('k') => {
+            Command::Up => {
                 self.description_offset = self.description_offset.saturating_sub(1);
             }
-            KeyCode::Char('t') => {
+            Command::TestCases => {
                 self.editor_state = EditorState::TestCases;
             }
-            KeyCode::Char('c') => {
+            Command::Language => {
                 self.editor_state = EditorState::SelectingLanguage;
             }
-            KeyCode::Char('e') => {
-                if self.selected_language.is_some() {
+            Command::OpenEditor => {
+                if self.selected_language.is_none() {
+                    self.error_message = Some("no language is selected".into());
+                } else if self.code_editor.is_some() {
+                    self.open_code_editor();
+                } else if self.has_editor_session() {
                     return UpdateResult::OpenEditor;
+                } else if let Some(pane) = self.editor_pane() {
+                    if let Err(e) = pane.focus() {
+                        self.error_message = Some(e.to_string());
+                    }
                 } else {
-                    self.error_message = Some("no language is selected".into());
+                    match ExternalEditor::resolve(self.config.editor.as_deref()) {
+                        Some(editor) if editor.is_gui() => self.open_gui_editor(&editor),
+                        Some(editor) => {
+                            // Suspending is the fallback for when the pane can't be opened,
+                            // like in kitty without remote control.
+                            let opened = self
+                                .config
+                                .features
+                                .editor_panes
+                                .then(Multiplexer::detect)
+                                .flatten()
+                                .is_some_and(|mux| self.open_editor_pane(&editor, mux).is_ok());
+
+                            if !opened {
+                                return UpdateResult::OpenEditor;
+                            }
+                        }
+                        None => self.open_code_editor(),
+                    }
                 }
             }
-            KeyCode::Char('s') => {} // submit code
-            KeyCode::Char('r') => {} // run tests
+            Command::CodeEditor => self.open_code_editor(),
+            Command::Files => self.open_solution_picker(),
+            Command::Submit => self.submit_code(),
+            Command::RunTests => self.run_tests(),
+            Command::OpenImage => self.open_image(),
+            Command::TogglePreview => self.show_preview = !self.show_preview,
+            Command::PreviewDown => {
+                self.preview_offset = self.preview_offset.saturating_add(1);
+            }
+            Command::PreviewUp => {
+                self.preview_offset = self.preview_offset.saturating_sub(1);
+            }
             _ => {}
         }
 
         UpdateResult::Continue
     }
 
-    fn handle_editor_test_cases_key(&mut self, key: KeyEvent) {
+    fn handle_test_cases_command(&mut self, command: Command) {
         let Some(ref question) = self.question else {
             unreachable!()
         };
 
-        match key.code {
-            KeyCode::Char('h') => {
+        match command {
+            Command::Left if !self.test_cases.is_empty() => {
                 if self.selected_test_case == 0 {
                     self.selected_test_case = self.test_cases.len() - 1;
                 } else {
                     self.selected_test_case -= 1;
                 }
             }
-            KeyCode::Char('j') => {
-                if self.selected_case_text < question.meta_data.params.len() - 1 {
-                    self.selected_case_text += 1;
-                    self.adjust_scroll_for_selection();
-                }
+            Command::Down if self.selected_case_text < question.meta_data.params.len() - 1 => {
+                self.selected_case_text += 1;
+                self.adjust_scroll_for_selection();
             }
-            KeyCode::Char('k') => {
-                if self.selected_case_text > 0 {
-                    self.selected_case_text -= 1;
-                    self.adjust_scroll_for_selection();
-                }
+            Command::Up if self.selected_case_text > 0 => {
+                self.selected_case_text -= 1;
+                self.adjust_scroll_for_selection();
             }
-            KeyCode::Char('l') => {
+            Command::Right if !self.test_cases.is_empty() => {
                 self.selected_test_case = (self.selected_test_case + 1) % self.test_cases.len();
             }
-            KeyCode::Enter => {
-                if !self.test_cases.is_empty() {
-                    self.editor_state = EditorState::EditingTestCaseField;
-                }
+            Command::Select if !self.test_cases.is_empty() => {
+                self.editor_state = EditorState::EditingTestCaseField;
             }
-            KeyCode::Char('d') => {
+            Command::DeleteCase if !self.test_cases.is_empty() => {
                 self.test_cases.remove(self.selected_test_case);
                 self.selected_test_case = self
                     .selected_test_case
                     .min(self.test_cases.len().saturating_sub(1));
+                self.save_test_cases();
             }
-            KeyCode::Char('a') => {
+            Command::AddCase => {
                 self.test_cases.push(TestCase {
                     input: vec!["".into(); question.meta_data.params.len()],
                     output: None,
                     expected: None,
                 });
+                self.save_test_cases();
             }
-            KeyCode::Char('c') => {
+            Command::ResetCases => self.reset_test_cases(),
+            Command::Language => {
                 self.editor_state = EditorState::SelectingLanguage;
             }
-            KeyCode::Esc | KeyCode::Char('t') => self.editor_state = EditorState::Description,
-            KeyCode::Char('s') => {} // submit code
-            KeyCode::Char('r') => {} // run tests
+            Command::Back => self.editor_state = EditorState::Description,
+            Command::Submit => self.submit_code(),
+            Command::RunTests => self.run_tests(),
             _ => {}
         }
     }
@@ -602,20 +1172,29 @@ impl App {
 
         let text = &mut case.input[self.selected_case_text];
 
-        match key.code {
-            KeyCode::Char(c) => text.push(c),
-            KeyCode::Backspace => {
+        match field_key(TEST_CASE_FIELD_KEYS, key) {
+            Some(FieldKey::DeleteChar) => {
                 text.pop();
+                case.output = None;
+                case.expected = None;
             }
-            KeyCode::Enter => {
+            Some(FieldKey::Confirm) => {
                 *text = text.trim().to_string();
                 self.editor_state = EditorState::Description;
+                self.save_test_cases();
             }
-            KeyCode::Esc => {
+            Some(FieldKey::Cancel) => {
                 *text = text.trim().to_string();
                 self.editor_state = EditorState::TestCases;
+                self.save_test_cases();
+            }
+            None => {
+                if let KeyCode::Char(c) = key.code {
+                    text.push(c);
+                    case.output = None;
+                    case.expected = None;
+                }
             }
-            _ => {}
         }
     }
 
@@ -632,14 +1211,17 @@ impl App {
 
         self.problem_table_state.select(Some(next));
 
-        let threshold = 25;
-        if next + threshold >= self.problems.len() && !self.is_loading && self.has_more {
+        if next + self.config.scroll_threshold() >= self.problems.len()
+            && !self.is_loading
+            && self.has_more
+        {
             self.is_loading = true;
             self.send_request(ClientRequest::FetchProblems {
                 skip: self.problems.len(),
-                limit: 50,
+                limit: self.config.page_size,
                 search: (!self.search_bar_input.is_empty())
                     .then_some(self.search_bar_input.clone()),
+                difficulty: self.difficulty_filter,
             });
         }
     }
@@ -652,20 +1234,14 @@ impl App {
         let i = self
             .problem_table_state
             .selected()
-            .map(|i| i.saturating_sub(amount).max(0))
+            .map(|i| i.saturating_sub(amount))
             .unwrap_or_default();
 
         self.problem_table_state.select(Some(i));
     }
 
     pub fn adjust_scroll_for_selection(&mut self) {
-        let param_count = self
-            .question
-            .as_ref()
-            .map(|q| q.meta_data.params.len())
-            .unwrap_or_default();
-
-        let total_content_height = (5 * param_count) as u16;
+        let total_content_height = (5 * self.test_case_field_count()) as u16;
         let viewport_height = self.last_test_case_viewport_height;
 
         let max_scroll = total_content_height.saturating_sub(viewport_height);
@@ -683,67 +1259,1190 @@ impl App {
         self.test_cases_scroll_offset = new_offset.min(max_scroll) as usize;
     }
 
-    /// Sends a client request to the client handler
-    ///
-    /// # Arguments
-    /// * `req` - The request to send.
-    fn send_request(&self, req: ClientRequest) {
-        let tx = self.client_tx.clone();
-        tokio::spawn(async move {
-            let _ = tx.send(req).await;
-        });
-    }
+    /// Saves the test cases into the problem's workspace.
+    fn save_test_cases(&mut self) {
+        let question = self.question.as_ref().unwrap();
+        let dir_path = workspace::problem_dir(&question.title_slug);
 
-    fn load_local_file_paths(&mut self, question: &Question) -> io::Result<()> {
-        let slug = &question.title_slug;
-        let dir_path = env::home_dir()
-            .unwrap_or_default()
-            .join(".leetui")
-            .join(slug);
+        if let Err(e) = workspace::save_test_cases(&dir_path, &self.test_cases) {
+            self.error_message = Some(e.to_string());
+        }
+    }
 
-        fs::create_dir_all(&dir_path)?;
+    /// Replaces the test cases by the problem's examples, forgetting the saved ones.
+    fn reset_test_cases(&mut self) {
+        let question = self.question.as_ref().unwrap();
+        let dir_path = workspace::problem_dir(&question.title_slug);
 
-        self.solution_paths = fs::read_dir(&dir_path)?
-            .flatten()
-            .map(|file| file.path())
-            .collect();
+        self.test_cases = self.default_test_cases.clone();
+        self.selected_test_case = 0;
+        self.run_status = None;
 
-        Ok(())
+        if let Err(e) = workspace::remove_test_cases(&dir_path) {
+            self.error_message = Some(e.to_string());
+        }
     }
 
-    pub fn open_editor(&mut self) -> io::Result<()> {
-        let child = match self.editor_subprocess.take() {
-            Some(child) => {
-                let pid = child.id() as i32;
-                unsafe { libc::kill(pid, libc::SIGCONT) };
-                child
-            }
-            None => {
-                let slug = &self.question.as_ref().unwrap().title_slug;
+    /// Counts the fields shown for the selected test case, inputs and results alike.
+    pub fn test_case_field_count(&self) -> usize {
+        let param_count = self
+            .question
+            .as_ref()
+            .map(|q| q.meta_data.params.len())
+            .unwrap_or_default();
 
-                let leetui_dir = env::home_dir().unwrap_or_default().join(".leetui");
-                let dir_path = leetui_dir.join(slug);
+        param_count + self.result_fields().len()
+    }
 
-                fs::create_dir_all(&dir_path)?;
-                let lang = self.selected_language.as_ref().unwrap();
-                let file_name = format!("solution.{}", lang.ext());
-                let file_path = dir_path.join(&file_name);
+    /// Collects the results to show below the inputs of the selected test case.
+    pub fn result_fields(&self) -> Vec<ResultField<'_>> {
+        let mut fields = Vec::new();
+        let Some(case) = self.test_cases.get(self.selected_test_case) else {
+            return fields;
+        };
 
-                if !file_path.exists() {
-                    let question = self.question.as_ref().unwrap();
-                    let code_snippet = question
-                        .code_snippets
-                        .iter()
-                        .find(|cs| cs.lang == *lang)
-                        .unwrap();
+        if let Some(ref expected) = case.expected {
+            fields.push(ResultField {
+                label: "expected",
+                value: expected,
+                passed: None,
+            });
+        }
 
-                    let code = &code_snippet.code;
-                    fs::write(&file_path, &code)?;
-                }
+        if let Some(ref output) = case.output {
+            fields.push(ResultField {
+                label: "output",
+                value: output,
+                passed: case.passed(),
+            });
+        }
+
+        if let Some(details) = self.run_status.as_ref().and_then(|s| s.details.as_ref()) {
+            fields.push(ResultField {
+                label: "error",
+                value: details,
+                passed: Some(false),
+            });
+        }
+
+        fields
+    }
+
+    /// Reads the solution for the selected language and sends it to be tested.
+    ///
+    /// Nothing is sent while any test case has an invalid input, instead the
+    /// first one is selected so it can be fixed.
+    fn run_tests(&mut self) {
+        if let Err(e) = self.save_code_editor() {
+            self.error_message = Some(e.to_string());
+            return;
+        }
+
+        if let Some((case, field, error)) = self.first_invalid_input() {
+            self.selected_test_case = case;
+            self.selected_case_text = field;
+            self.editor_state = EditorState::TestCases;
+            self.adjust_scroll_for_selection();
+            self.error_message = Some(format!("case {}: {error}", case + 1));
+            return;
+        }
+
+        let test_cases = self
+            .test_cases
+            .iter()
+            .flat_map(|case| &case.input)
+            .map(String::as_str)
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        match self.solution_request_parts() {
+            Ok((slug, question_id, lang, code)) => {
+                for case in &mut self.test_cases {
+                    case.output = None;
+                }
+
+                self.is_loading = true;
+                self.run_status = None;
+                self.send_request(ClientRequest::RunTests {
+                    slug,
+                    question_id,
+                    lang,
+                    code,
+                    test_cases,
+                });
+            }
+            Err(e) => self.error_message = Some(e.to_string()),
+        }
+    }
+
+    /// Finds the first test case input that doesn't match its parameter's type.
+    ///
+    /// # Returns
+    /// The index of the case, the index of the input and the validation error.
+    fn first_invalid_input(&self) -> Option<(usize, usize, String)> {
+        let params = &self.question.as_ref()?.meta_data.params;
+
+        self.test_cases.iter().enumerate().find_map(|(i, case)| {
+            params
+                .iter()
+                .zip(&case.input)
+                .enumerate()
+                .find_map(|(j, (param, value))| {
+                    let error = param.validate(value).err()?;
+                    Some((i, j, format!("{}: {error}", param.name)))
+                })
+        })
+    }
+
+    /// Reads the solution for the selected language and submits it.
+    fn submit_code(&mut self) {
+        if let Err(e) = self.save_code_editor() {
+            self.error_message = Some(e.to_string());
+            return;
+        }
+
+        match self.solution_request_parts() {
+            Ok((slug, question_id, lang, code)) => {
+                self.is_loading = true;
+                self.run_status = None;
+                self.send_request(ClientRequest::SubmitCode {
+                    slug,
+                    question_id,
+                    lang,
+                    code,
+                });
+            }
+            Err(e) => self.error_message = Some(e.to_string()),
+        }
+    }
+
+    /// Gathers what LeetCode needs to judge the solution for the selected language.
+    ///
+    /// # Returns
+    /// The slug, question id, language slug and code of the solution.
+    fn solution_request_parts(&self) -> io::Result<(String, String, String, String)> {
+        let question = self.question.as_ref().unwrap();
+        let Some(lang) = self.selected_language else {
+            return Err(io::Error::other("no language is selected"));
+        };
+
+        let lang_slug = question
+            .code_snippets
+            .iter()
+            .find(|cs| cs.lang == lang)
+            .map(|cs| cs.lang_slug.clone())
+            .unwrap_or_default();
+
+        let code = fs::read_to_string(self.solution_file().unwrap())?;
+        let code = workspace::strip_local_only(&code);
+
+        Ok((
+            question.title_slug.clone(),
+            question.question_id.clone(),
+            lang_slug,
+            code,
+        ))
+    }
+
+    /// Stores the outcome of running the test cases.
+    ///
+    /// # Arguments
+    /// * `res` - The judged run.
+    fn handle_tests_checked(&mut self, res: TestCasesCheckResponse) {
+        let answers = res.code_answer.unwrap_or_default();
+        let reference = res.expected_code_answer.unwrap_or_default();
+
+        for (i, case) in self.test_cases.iter_mut().enumerate() {
+            case.output = answers.get(i).cloned();
+            if case.expected.is_none() {
+                case.expected = reference.get(i).cloned();
+            }
+        }
+
+        let details = res.full_compile_error.or(res.full_runtime_error);
+        let failed = self.test_cases.iter().any(|c| c.passed() == Some(false));
+
+        // LeetCode accepts any run that finishes, regardless of the outputs.
+        let status = match res.status_msg.unwrap_or(StatusMsg::Unknown) {
+            StatusMsg::Accepted if failed => StatusMsg::WrongAnswer,
+            status => status,
+        };
+
+        let title = match res.status_runtime {
+            Some(runtime) => format!("{status} · {runtime}"),
+            None => status.to_string(),
+        };
+
+        self.run_status = Some(RunStatus {
+            accepted: status == StatusMsg::Accepted && details.is_none(),
+            title,
+            details,
+        });
+    }
+
+    /// Stores the outcome of a submission.
+    ///
+    /// # Arguments
+    /// * `res` - The judged submission.
+    fn handle_submission_checked(&mut self, res: SubmissionCheckResponse) {
+        let status = res.status_msg.unwrap_or(StatusMsg::Unknown);
+        let mut title = status.to_string();
+
+        if let (Some(correct), Some(total)) = (res.total_correct, res.total_testcases) {
+            title.push_str(&format!(" · {correct}/{total}"));
+        }
+
+        if status == StatusMsg::Accepted {
+            for (stat, percentile) in [
+                (res.status_runtime, res.runtime_percentile),
+                (res.status_memory, res.memory_percentile),
+            ] {
+                match (stat, percentile) {
+                    (Some(stat), Some(p)) => title.push_str(&format!(" · {stat} ({p:.0}%)")),
+                    (Some(stat), None) => title.push_str(&format!(" · {stat}")),
+                    _ => {}
+                }
+            }
+        }
+
+        let details = res
+            .full_compile_error
+            .or(res.full_runtime_error)
+            .or_else(|| {
+                let input = res.input_formatted?;
+                let expected = res.expected_output.unwrap_or_default();
+                let output = res.code_answer.unwrap_or_default().join(", ");
+                Some(format!("{input} → expected {expected}, got {output}"))
+            });
+
+        self.run_status = Some(RunStatus {
+            accepted: status == StatusMsg::Accepted,
+            title,
+            details,
+        });
+    }
+
+    /// Reads the solution for the selected language again, falling back to
+    /// the template snippet if it wasn't written yet.
+    fn reload_preview(&mut self) {
+        let (Some(question), Some(lang)) = (self.question.as_ref(), self.selected_language) else {
+            self.preview = None;
+            return;
+        };
+
+        let path = self.solution_file().unwrap();
+
+        let (source, code) = match fs::read_to_string(&path) {
+            Ok(code) => {
+                let name = path.file_name().unwrap_or_default().to_string_lossy();
+                (name.into_owned(), code)
+            }
+            Err(_) => {
+                let snippet = question.code_snippets.iter().find(|cs| cs.lang == lang);
+                let code = snippet.map(|cs| cs.code.clone()).unwrap_or_default();
+                ("template".to_string(), code)
+            }
+        };
+
+        self.preview = Some(SolutionPreview {
+            source,
+            lines: rendering::highlight(&code, Some(lang), &self.theme),
+        });
+
+        if self
+            .solution_watcher
+            .as_ref()
+            .is_none_or(|w| w.path() != path)
+        {
+            self.solution_watcher = Some(FileWatcher::new(path));
+        }
+    }
+
+    /// Checks whether the solution was written outside of leetui, e.g. by an
+    /// editor in another pane, and shows the new contents.
+    ///
+    /// # Returns
+    /// Whether the solution changed.
+    fn poll_solution(&mut self) -> bool {
+        self.editor_panes.retain(|_, pane| pane.is_alive());
+        if matches!(self.state, AppState::Home) && !self.has_outside_editor() {
+            self.solution_watcher = None;
+        }
+
+        let Some(ref mut watcher) = self.solution_watcher else {
+            return false;
+        };
+
+        if !watcher.changed() {
+            return false;
+        }
+
+        if let Some(ref mut editor) = self.code_editor
+            && editor.path == watcher.path()
+            && let Err(e) = editor.reload()
+        {
+            self.error_message = Some(e.to_string());
+        }
+
+        self.reload_preview();
+        true
+    }
+
+    /// Requests the images of the description that haven't been fetched yet.
+    fn fetch_images(&mut self) {
+        let urls: Vec<_> = self
+            .description
+            .images()
+            .into_iter()
+            .map(|image| image.src.clone())
+            .collect();
+
+        for url in urls {
+            if self.images.start_loading(&url) {
+                self.send_request(ClientRequest::FetchImage { url });
+            }
+        }
+    }
+
+    /// Opens the first image on screen with the system's viewer, or the
+    /// first one of the description if none is visible.
+    fn open_image(&mut self) {
+        let url = self.visible_images.first().cloned().or_else(|| {
+            let images = self.description.images();
+            images.first().map(|image| image.src.clone())
+        });
+
+        let Some(url) = url else {
+            self.error_message = Some("the description has no images".into());
+            return;
+        };
+
+        if let Err(e) = images::open(&url) {
+            self.error_message = Some(e.to_string());
+        }
+    }
+
+    /// Sends a client request to the client handler
+    ///
+    /// # Arguments
+    /// * `req` - The request to send.
+    fn send_request(&self, req: ClientRequest) {
+        let tx = self.client_tx.clone();
+        tokio::spawn(async move {
+            let _ = tx.send(req).await;
+        });
+    }
+
+    fn load_local_file_paths(&mut self, question: &Question) -> io::Result<()> {
+        let dir_path = workspace::problem_dir(&question.title_slug);
+        fs::create_dir_all(&dir_path)?;
+
+        self.solution_paths = workspace::list_solutions(&dir_path)?;
+        Ok(())
+    }
+
+    /// Returns the solution file being worked on, which is the picked one or
+    /// the default one of the selected language.
+    ///
+    /// # Returns
+    /// The path of the file, or `None` if no language is selected.
+    fn solution_file(&self) -> Option<PathBuf> {
+        if let Some(ref path) = self.selected_solution {
+            return Some(path.clone());
+        }
+
+        let question = self.question.as_ref()?;
+        let lang = self.selected_language?;
+        let dir_path = workspace::problem_dir(&question.title_slug);
+        Some(workspace::solution_path(&dir_path, lang.ext()))
+    }
+
+    /// Shows the solutions of the problem to pick one, selecting the current one.
+    fn open_solution_picker(&mut self) {
+        let dir_path = workspace::problem_dir(&self.question.as_ref().unwrap().title_slug);
+        match workspace::list_solutions(&dir_path) {
+            Ok(paths) => self.solution_paths = paths,
+            Err(e) => {
+                self.error_message = Some(e.to_string());
+                return;
+            }
+        }
+
+        let current = self.solution_file();
+        self.solution_selection_index = self
+            .solution_paths
+            .iter()
+            .position(|path| Some(path) == current.as_ref())
+            .unwrap_or_default();
+
+        self.editor_state = EditorState::SelectingSolution;
+    }
+
+    fn handle_solutions_command(&mut self, command: Command) {
+        let count = self.solution_paths.len();
+
+        match command {
+            Command::Back => self.editor_state = EditorState::Description,
+            Command::Down if self.solution_selection_index + 1 < count => {
+                self.solution_selection_index += 1;
+            }
+            Command::Up => {
+                self.solution_selection_index = self.solution_selection_index.saturating_sub(1);
+            }
+            Command::NewSolution => {
+                self.new_solution_name.clear();
+                self.editor_state = EditorState::NamingSolution;
+            }
+            Command::Select if count > 0 => {
+                let path = self.solution_paths[self.solution_selection_index].clone();
+                let selected = match workspace::solution_language(&path) {
+                    Some(lang) => self.select_solution(path, lang),
+                    None => Err(io::Error::other(
+                        "the language can't be inferred from the extension",
+                    )),
+                };
+
+                match selected {
+                    Ok(()) => self.editor_state = EditorState::Description,
+                    Err(e) => self.error_message = Some(e.to_string()),
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn handle_editor_naming_solution_key(&mut self, key: KeyEvent) {
+        match field_key(SOLUTION_NAME_KEYS, key) {
+            Some(FieldKey::DeleteChar) => {
+                self.new_solution_name.pop();
+            }
+            Some(FieldKey::Cancel) => self.editor_state = EditorState::SelectingSolution,
+            Some(FieldKey::Confirm) => match self.create_solution() {
+                Ok(()) => self.editor_state = EditorState::Description,
+                Err(e) => self.error_message = Some(e.to_string()),
+            },
+            None => {
+                if let KeyCode::Char(ch) = key.code {
+                    self.new_solution_name.push(ch);
+                }
+            }
+        }
+    }
+
+    /// Makes a solution file the one to edit, run and submit.
+    ///
+    /// # Arguments
+    /// * `path` - The path of the solution.
+    /// * `lang` - The language the solution is written in.
+    fn select_solution(&mut self, path: PathBuf, lang: Language) -> io::Result<()> {
+        self.release_code_editor(&path)?;
+        let question = self.question.as_ref().unwrap();
+
+        if !question.code_snippets.iter().any(|cs| cs.lang == lang) {
+            return Err(io::Error::other(format!(
+                "{lang} isn't available for this problem"
+            )));
+        }
+
+        // The project files point at a single solution, so they have to follow the picked one.
+        if let Some(dir_path) = path.parent().filter(|_| path.exists()) {
+            workspace::write_scaffold(dir_path, &question.title_slug, &path, lang)?;
+        }
+
+        self.selected_language = Some(lang);
+        self.selected_solution = Some(path);
+        self.reload_preview();
+        Ok(())
+    }
+
+    /// Creates a new solution from the template of its language, named as typed.
+    ///
+    /// Names without an extension get the one of the selected language.
+    fn create_solution(&mut self) -> io::Result<()> {
+        let name = self.new_solution_name.trim();
+        if name.is_empty() || name.contains('/') {
+            return Err(io::Error::other("the name must be a file name"));
+        }
+
+        let name = match (Path::new(name).extension(), self.selected_language) {
+            (Some(_), _) => name.to_string(),
+            (None, Some(lang)) => format!("{name}.{}", lang.ext()),
+            (None, None) => return Err(io::Error::other("add an extension to pick the language")),
+        };
+
+        // Python 2 and 3 share the extension, so the selected one is kept if it fits.
+        let ext = Path::new(&name).extension().unwrap_or_default();
+        let lang = match self.selected_language {
+            Some(lang) if ext == lang.ext() => lang,
+            _ => ext
+                .to_str()
+                .and_then(Language::from_ext)
+                .filter(|lang| *lang != Language::Unknown)
+                .ok_or_else(|| {
+                    io::Error::other("the language can't be inferred from the extension")
+                })?,
+        };
+
+        let question = self.question.as_ref().unwrap();
+        let dir_path = workspace::problem_dir(&question.title_slug);
+        let path = workspace::named_solution_path(&dir_path, &name, lang);
+        if path.exists() {
+            return Err(io::Error::other(format!(
+                "{} already exists",
+                path.display()
+            )));
+        }
+
+        self.select_solution(path.clone(), lang)?;
+        self.prepare_solution()?;
+        self.solution_paths.push(path);
+        self.solution_paths.sort();
+        self.reload_preview();
+        Ok(())
+    }
+
+    /// Sets up the workspace of the problem for the selected solution,
+    /// writing the template snippet if it doesn't exist yet.
+    ///
+    /// # Returns
+    /// The path of the solution file.
+    fn prepare_solution(&self) -> io::Result<PathBuf> {
+        let question = self.question.as_ref().unwrap();
+        let dir_path = workspace::problem_dir(&question.title_slug);
+
+        let lang = self.selected_language.as_ref().unwrap();
+        let file_path = self.solution_file().unwrap();
+
+        // Named Go solutions are in a directory of their own, which gets its own project.
+        let solution_dir = file_path.parent().unwrap_or(&dir_path);
+        fs::create_dir_all(solution_dir)?;
+
+        workspace::write_readme(&dir_path, question)?;
+        workspace::write_support_files(solution_dir, question, *lang)?;
+        workspace::write_scaffold(solution_dir, &question.title_slug, &file_path, *lang)?;
+
+        if !file_path.exists() {
+            let code_snippet = question
+                .code_snippets
+                .iter()
+                .find(|cs| cs.lang == *lang)
+                .unwrap();
+
+            let code = workspace::render_solution(question, *lang, &code_snippet.code)?;
+            let mut code = workspace::with_package_clause(&code, *lang);
+            if self.config.features.description_header {
+                code = workspace::with_description_header(&code, question, *lang);
+            }
+
+            let code = workspace::with_language_marker(&code, *lang);
+            fs::write(&file_path, code)?;
+        }
+
+        Ok(file_path)
+    }
+
+    /// Opens the solution for the selected language in the built-in editor,
+    /// or focuses it if it's already open.
+    fn open_code_editor(&mut self) {
+        if self.code_editor.is_none() {
+            let Some(lang) = self.selected_language else {
+                self.error_message = Some("no language is selected".into());
+                return;
+            };
+
+            let editor = self
+                .prepare_solution()
+                .and_then(|path| CodeEditor::open(path, lang));
+
+            match editor {
+                Ok(editor) => self.code_editor = Some(editor),
+                Err(e) => {
+                    self.error_message = Some(e.to_string());
+                    return;
+                }
+            }
+        }
+
+        self.editor_state = EditorState::Coding;
+    }
+
+    /// Saves the built-in editor's buffer if it has changes, so the solution
+    /// read from disk is the one being edited.
+    fn save_code_editor(&mut self) -> io::Result<()> {
+        let Some(ref mut editor) = self.code_editor else {
+            return Ok(());
+        };
+
+        if editor.is_modified() {
+            editor.save()?;
+            self.reload_preview();
+        }
+
+        Ok(())
+    }
+
+    /// Closes the built-in editor if it's open on another file than the
+    /// solution about to be worked on.
+    ///
+    /// # Arguments
+    /// * `next` - The path of the solution about to be worked on.
+    ///
+    /// # Returns
+    /// An error, keeping the editor open, if it has unsaved changes.
+    fn release_code_editor(&mut self, next: &Path) -> io::Result<()> {
+        let Some(ref editor) = self.code_editor else {
+            return Ok(());
+        };
+
+        if editor.path == next {
+            return Ok(());
+        }
+
+        if editor.is_modified() {
+            return Err(io::Error::other(format!(
+                "{} has unsaved changes",
+                editor.file_name()
+            )));
+        }
+
+        self.code_editor = None;
+        Ok(())
+    }
+
+    fn handle_editor_coding_key(&mut self, key: KeyEvent) {
+        let Some(ref mut editor) = self.code_editor else {
+            self.editor_state = EditorState::Description;
+            return;
+        };
+
+        let (save, close) = match editor.handle_key(key) {
+            EditorCommand::Nothing => (false, false),
+            EditorCommand::Save => (true, false),
+            EditorCommand::SaveAndClose => (true, true),
+            EditorCommand::Close => (false, true),
+            EditorCommand::Blur => {
+                self.editor_state = EditorState::Description;
+                return;
+            }
+        };
+
+        if save && let Err(e) = editor.save() {
+            self.error_message = Some(e.to_string());
+            return;
+        }
+
+        if save {
+            self.reload_preview();
+        }
+
+        if close {
+            self.code_editor = None;
+            self.editor_state = EditorState::Description;
+        }
+    }
+
+    /// Opens the solution in an editor with a window of its own, which
+    /// doesn't need the terminal so leetui keeps running alongside it.
+    ///
+    /// # Arguments
+    /// * `editor` - The editor to open.
+    fn open_gui_editor(&mut self, editor: &ExternalEditor) {
+        let spawned = self.prepare_solution().and_then(|file_path| {
+            let (line, column) = external_editor::jump_target(&fs::read_to_string(&file_path)?);
+            editor
+                .command(&file_path, line, column)
+                .current_dir(workspace::root_dir())
+                .stdin(Stdio::null())
+                .stdout(Stdio::null())
+                .stderr(Stdio::null())
+                .spawn()
+        });
+
+        match spawned {
+            Ok(child) => self.gui_editor = Some(child),
+            Err(e) => self.error_message = Some(e.to_string()),
+        }
+    }
+
+    /// Opens the solution in a pane next to leetui, so the description stays
+    /// visible while coding.
+    ///
+    /// # Arguments
+    /// * `editor` - The terminal editor to open.
+    /// * `mux` - The multiplexer to open the pane with.
+    fn open_editor_pane(&mut self, editor: &ExternalEditor, mux: Multiplexer) -> io::Result<()> {
+        let file_path = self.prepare_solution()?;
+        let (line, column) = external_editor::jump_target(&fs::read_to_string(&file_path)?);
+        let argv = editor.argv(&file_path, line, column);
+
+        let slug = self.question.as_ref().unwrap().title_slug.clone();
+        let pid_file = env::temp_dir().join(format!("leetui-{}-{slug}.pid", process::id()));
+
+        // The closed pane would remove the pid file of the new one when dropped.
+        self.editor_panes.remove(&slug);
+        let pane = mux.open(&argv, &workspace::root_dir(), pid_file)?;
+        self.editor_panes.insert(slug, pane);
+
+        self.reload_preview();
+        Ok(())
+    }
+
+    /// Returns the pane the open problem's editor is still running in, if any.
+    fn editor_pane(&self) -> Option<&EditorPane> {
+        let slug = &self.question.as_ref()?.title_slug;
+        self.editor_panes.get(slug).filter(|pane| pane.is_alive())
+    }
+
+    /// Checks whether the windowed editor was closed, to show the saved solution.
+    ///
+    /// # Returns
+    /// Whether the editor was closed.
+    fn poll_gui_editor(&mut self) -> bool {
+        let Some(ref mut child) = self.gui_editor else {
+            return false;
+        };
+
+        match child.try_wait() {
+            Ok(None) => false,
+            Ok(Some(_)) | Err(_) => {
+                self.gui_editor = None;
+                self.reload_preview();
+                true
+            }
+        }
+    }
+
+    /// Whether an editor or solution file is open that could change without
+    /// leetui knowing, or a closed editor has yet to exit, so it has to be polled.
+    pub fn is_watching(&self) -> bool {
+        self.gui_editor.is_some()
+            || self.solution_watcher.is_some()
+            || !self.hung_up_editors.is_empty()
+    }
+
+    /// Returns the scope whose bindings apply in the current state, or `None`
+    /// while text is being typed.
+    pub fn key_scope(&self) -> Option<Scope> {
+        match self.state {
+            AppState::Home => match self.home_input_state {
+                HomeInputState::Normal => Some(Scope::Home),
+                HomeInputState::Searching => None,
+                HomeInputState::Sessions { .. } => Some(Scope::Sessions),
+            },
+            AppState::Editor => match self.editor_state {
+                EditorState::Description => Some(Scope::Description),
+                EditorState::TestCases => Some(Scope::TestCases),
+                EditorState::SelectingLanguage => Some(Scope::Languages),
+                EditorState::SelectingSolution => Some(Scope::Solutions),
+                EditorState::EditingTestCaseField
+                | EditorState::NamingSolution
+                | EditorState::Coding => None,
+            },
+        }
+    }
+
+    /// Returns the state text is being typed in, whose keys are fixed, or
+    /// `None` while the keys of a scope apply.
+    pub fn text_input(&self) -> Option<TextInput> {
+        match self.state {
+            AppState::Home => match self.home_input_state {
+                HomeInputState::Searching => Some(TextInput::Search),
+                _ => None,
+            },
+            AppState::Editor => match self.editor_state {
+                EditorState::EditingTestCaseField => Some(TextInput::TestCaseField),
+                EditorState::NamingSolution => Some(TextInput::SolutionName),
+                EditorState::Coding => Some(TextInput::CodeEditor),
+                _ => None,
+            },
+        }
+    }
+
+    /// Whether a command does anything right now, so it's left out of the help otherwise.
+    ///
+    /// # Arguments
+    /// * `command` - The command to check.
+    pub fn command_available(&self, command: Command) -> bool {
+        match command {
+            Command::PreviewDown | Command::PreviewUp => self.show_preview,
+            Command::OpenImage => !self.description.images().is_empty(),
+            Command::TerminateAll => {
+                matches!(
+                    self.home_input_state,
+                    HomeInputState::Sessions { exiting: true }
+                )
+            }
+            _ => true,
+        }
+    }
+
+    /// Opens the command palette with the commands of the current scope and
+    /// the actions that have no key of their own.
+    fn open_palette(&mut self) {
+        let Some(scope) = self.key_scope() else {
+            return;
+        };
+
+        let mut entries: Vec<_> = self
+            .keymap
+            .bindings(scope)
+            .iter()
+            .map(|(command, _)| *command)
+            .filter(|command| command.in_palette() && self.command_available(*command))
+            .map(|command| PaletteEntry {
+                label: command.description().into(),
+                keys: self.keymap.describe(scope, &[command]),
+                action: PaletteAction::Command(command),
+            })
+            .collect();
+
+        if self.palette_problem_slug().is_some() {
+            entries.push(PaletteEntry {
+                label: "Open the problem in the browser".into(),
+                keys: None,
+                action: PaletteAction::OpenInBrowser,
+            });
+        }
+
+        if let Some(ref daily) = self.daily_challenge
+            && scope != Scope::Home
+        {
+            entries.push(PaletteEntry {
+                label: Command::Daily.description().into(),
+                keys: None,
+                action: PaletteAction::OpenProblem(daily.title_slug.clone()),
+            });
+        }
+
+        if scope == Scope::Home {
+            let filters = [
+                (Some(Difficulty::Easy), "Show only easy problems"),
+                (Some(Difficulty::Medium), "Show only medium problems"),
+                (Some(Difficulty::Hard), "Show only hard problems"),
+                (None, "Show problems of every difficulty"),
+            ];
+
+            entries.extend(
+                filters
+                    .into_iter()
+                    .filter(|(difficulty, _)| *difficulty != self.difficulty_filter)
+                    .map(|(difficulty, label)| PaletteEntry {
+                        label: label.into(),
+                        keys: None,
+                        action: PaletteAction::Filter(difficulty),
+                    }),
+            );
+        }
+
+        // NO_COLOR takes precedence over any theme.
+        if !theme::no_color() {
+            entries.extend(
+                THEMES
+                    .iter()
+                    .filter(|name| **name != self.theme.name)
+                    .map(|name| PaletteEntry {
+                        label: format!("Switch to the {name} theme"),
+                        keys: None,
+                        action: PaletteAction::Theme(name),
+                    }),
+            );
+        }
+
+        self.palette = Some(Palette::new(entries));
+    }
+
+    /// Handles an incoming key event while the command palette is open.
+    ///
+    /// # Arguments
+    /// * `key` - The incoming key event.
+    fn handle_palette_key(&mut self, key: KeyEvent) -> UpdateResult {
+        let Some(ref mut palette) = self.palette else {
+            return UpdateResult::Continue;
+        };
+
+        let ctrl = key.modifiers.contains(KeyModifiers::CONTROL);
+        match key.code {
+            KeyCode::Esc => self.palette = None,
+            KeyCode::Enter => {
+                let entry = palette.selected_entry().cloned();
+                self.palette = None;
+
+                if let Some(entry) = entry {
+                    return self.run_palette_action(entry.action);
+                }
+            }
+            KeyCode::Up | KeyCode::BackTab => palette.select_previous(),
+            KeyCode::Down | KeyCode::Tab => palette.select_next(),
+            KeyCode::Char('p') if ctrl => palette.select_previous(),
+            KeyCode::Char('n') if ctrl => palette.select_next(),
+            KeyCode::Backspace => palette.pop(),
+            KeyCode::Char(ch) if !ctrl => palette.push(ch),
+            _ => {}
+        }
+
+        UpdateResult::Continue
+    }
+
+    /// Handles an incoming key event while the help overlay is open, which
+    /// scrolls with the movement keys of the scope below and the arrows.
+    ///
+    /// # Arguments
+    /// * `key` - The incoming key event.
+    fn handle_help_key(&mut self, key: KeyEvent) {
+        let command = self
+            .key_scope()
+            .and_then(|scope| self.keymap.command(scope, key));
+
+        match (command, key.code) {
+            (Some(Command::Help | Command::Back | Command::Quit), _)
+            | (_, KeyCode::Esc | KeyCode::F(1)) => {
+                self.show_help = false;
+            }
+            (Some(Command::Down), _) | (_, KeyCode::Down) => {
+                self.help_offset = self.help_offset.saturating_add(1);
+            }
+            (Some(Command::Up), _) | (_, KeyCode::Up) => {
+                self.help_offset = self.help_offset.saturating_sub(1);
+            }
+            (Some(Command::PageDown), _) | (_, KeyCode::PageDown) => {
+                self.help_offset = self.help_offset.saturating_add(10);
+            }
+            (Some(Command::PageUp), _) | (_, KeyCode::PageUp) => {
+                self.help_offset = self.help_offset.saturating_sub(10);
+            }
+            _ => {}
+        }
+    }
+
+    /// Handles a click or a turn of the wheel on whatever was drawn under the
+    /// pointer. Clicks are ignored while text is being typed.
+    ///
+    /// # Arguments
+    /// * `mouse` - The incoming mouse event.
+    fn handle_mouse(&mut self, mouse: MouseEvent) -> UpdateResult {
+        let position = Position::new(mouse.column, mouse.row);
+        let target = self
+            .mouse_targets
+            .iter()
+            .rev()
+            .find(|(area, _)| area.contains(position))
+            .map(|(_, target)| *target);
+
+        let click = mouse.kind == MouseEventKind::Down(MouseButton::Left);
+        let step = match mouse.kind {
+            MouseEventKind::ScrollDown => 3,
+            MouseEventKind::ScrollUp => -3,
+            _ => 0,
+        };
+
+        if self.show_help {
+            match click {
+                true => self.show_help = false,
+                false => self.help_offset = self.help_offset.saturating_add_signed(step),
+            }
+            return UpdateResult::Continue;
+        }
+
+        if let Some(ref mut palette) = self.palette {
+            match (click, target) {
+                (true, Some(MouseTarget::PaletteEntry(i))) => {
+                    let entry = palette.visible().get(i).cloned();
+                    self.palette = None;
+
+                    if let Some(entry) = entry {
+                        return self.run_palette_action(entry.action);
+                    }
+                }
+                (true, _) => self.palette = None,
+                _ if step > 0 => palette.select_next(),
+                _ if step < 0 => palette.select_previous(),
+                _ => {}
+            }
+            return UpdateResult::Continue;
+        }
+
+        if step != 0 {
+            match target {
+                Some(MouseTarget::Problem(_) | MouseTarget::ProblemList) if step > 0 => {
+                    self.scroll_down_problem_list(step as usize);
+                }
+                Some(MouseTarget::Problem(_) | MouseTarget::ProblemList) => {
+                    self.scroll_up_problem_list(step.unsigned_abs());
+                }
+                Some(MouseTarget::Description) => {
+                    self.description_offset = self.description_offset.saturating_add_signed(step);
+                }
+                Some(MouseTarget::Preview) => {
+                    self.preview_offset = self.preview_offset.saturating_add_signed(step);
+                }
+                _ => return UpdateResult::SkipRendering,
+            }
+            return UpdateResult::Continue;
+        }
+
+        if !click || self.key_scope().is_none() {
+            return UpdateResult::SkipRendering;
+        }
+
+        match target {
+            Some(MouseTarget::Command(command)) => return self.run_command(command),
+            Some(MouseTarget::Problem(i)) if self.key_scope() == Some(Scope::Home) => {
+                let current = self.problem_table_state.selected().unwrap_or_default();
+                match i.cmp(&current) {
+                    Ordering::Equal => return self.run_command(Command::Select),
+                    Ordering::Greater => self.scroll_down_problem_list(i - current),
+                    Ordering::Less => self.scroll_up_problem_list(current - i),
+                }
+            }
+            Some(MouseTarget::Daily) => return self.run_command(Command::Daily),
+            Some(MouseTarget::TestCase(i)) => {
+                self.selected_test_case = i;
+                self.editor_state = EditorState::TestCases;
+            }
+            Some(MouseTarget::CaseField(i)) => {
+                self.selected_case_text = i;
+                self.adjust_scroll_for_selection();
+                self.editor_state = EditorState::EditingTestCaseField;
+            }
+            Some(MouseTarget::Language(i)) => {
+                self.language_selection_index = i;
+                return self.run_command(Command::Select);
+            }
+            _ => return UpdateResult::SkipRendering,
+        }
+
+        UpdateResult::Continue
+    }
 
-                let editor = env::var("EDITOR").map_err(|e| io::Error::other(e))?;
-                let mut cmd = Command::new(editor);
-                cmd.arg(file_path).current_dir(&leetui_dir);
+    /// Does what an entry of the command palette was chosen for.
+    ///
+    /// # Arguments
+    /// * `action` - The action of the chosen entry.
+    fn run_palette_action(&mut self, action: PaletteAction) -> UpdateResult {
+        match action {
+            PaletteAction::Command(command) => return self.run_command(command),
+            PaletteAction::OpenProblem(problem) => self.open_problem(problem),
+            PaletteAction::OpenInBrowser => {
+                let Some(slug) = self.palette_problem_slug() else {
+                    return UpdateResult::Continue;
+                };
+
+                if let Err(e) = open::that_detached(workspace::problem_url(slug)) {
+                    self.error_message = Some(e.to_string());
+                }
+            }
+            PaletteAction::Filter(difficulty) => {
+                self.difficulty_filter = difficulty;
+                self.problem_table_state.select(Some(0));
+                self.reload_problems();
+            }
+            PaletteAction::Theme(name) => {
+                self.theme = Theme::named(name).unwrap_or_default();
+                self.reload_preview();
+                if let Some(ref mut editor) = self.code_editor {
+                    editor.clear_highlight();
+                }
+            }
+        }
+
+        UpdateResult::Continue
+    }
+
+    /// Returns the slug of the problem the palette acts on, which is the open
+    /// one in the editor and the selected one at home.
+    fn palette_problem_slug(&self) -> Option<&str> {
+        match self.state {
+            AppState::Editor => self.question.as_ref().map(|q| q.title_slug.as_str()),
+            AppState::Home => self
+                .problem_table_state
+                .selected()
+                .and_then(|i| self.problems.get(i))
+                .map(|p| p.title_slug.as_str()),
+        }
+    }
+
+    /// Fetches a problem to open it, leaving the one open first since
+    /// problems are only loaded from home.
+    ///
+    /// # Arguments
+    /// * `problem` - The id or slug of the problem.
+    fn open_problem(&mut self, problem: String) {
+        if let AppState::Editor = self.state
+            && !self.leave_editor()
+        {
+            return;
+        }
+
+        self.home_input_state = HomeInputState::Normal;
+        self.is_loading = true;
+
+        match problem.chars().all(|ch| ch.is_ascii_digit()) {
+            true => self.send_request(ClientRequest::FetchQuestionById { id: problem }),
+            false => self.send_request(ClientRequest::FetchQuestion { slug: problem }),
+        }
+    }
+
+    /// Goes back home, unless the built-in editor has unsaved changes.
+    ///
+    /// # Returns
+    /// Whether the editor was left.
+    fn leave_editor(&mut self) -> bool {
+        if self
+            .code_editor
+            .as_ref()
+            .is_some_and(CodeEditor::is_modified)
+        {
+            self.error_message = Some("the solution has unsaved changes".into());
+            return false;
+        }
+
+        self.state = AppState::Home;
+        self.code_editor = None;
+
+        // Nothing can change the solution anymore, so there's no need to keep polling it.
+        if !self.has_outside_editor() {
+            self.solution_watcher = None;
+        }
+
+        true
+    }
+
+    /// Whether an editor other than the built-in one is still open on the
+    /// problem, so its solution may change.
+    fn has_outside_editor(&self) -> bool {
+        self.gui_editor.is_some()
+            || self.editor_pane().is_some()
+            || self.question.is_some() && self.has_editor_session()
+    }
+
+    /// Whether the open problem has a suspended editor.
+    fn has_editor_session(&self) -> bool {
+        let slug = &self.question.as_ref().unwrap().title_slug;
+        self.editor_sessions.contains_key(slug)
+    }
+
+    /// Runs the terminal editor in place of leetui until it exits or is
+    /// suspended, resuming the problem's suspended editor if it has one.
+    pub fn open_editor(&mut self) -> io::Result<()> {
+        let slug = self.question.as_ref().unwrap().title_slug.clone();
+
+        let (child, lang) = match self.editor_sessions.remove(&slug) {
+            Some(session) => {
+                // The editor's children, like a language server, were stopped with it.
+                let pgid = session.child.id() as i32;
+                unsafe { libc::kill(-pgid, libc::SIGCONT) };
+                (session.child, session.lang)
+            }
+            None => {
+                let leetui_dir = workspace::root_dir();
+                let file_path = self.prepare_solution()?;
+
+                let editor = ExternalEditor::resolve(self.config.editor.as_deref())
+                    .ok_or_else(|| io::Error::other("no editor was found"))?;
+
+                let (line, column) = external_editor::jump_target(&fs::read_to_string(&file_path)?);
+                let mut cmd = editor.command(&file_path, line, column);
+                cmd.current_dir(&leetui_dir);
 
                 unsafe {
                     cmd.pre_exec(|| {
@@ -753,7 +2452,7 @@ impl App {
                     });
                 }
 
-                cmd.spawn()?
+                (cmd.spawn()?, self.selected_language.unwrap())
             }
         };
 
@@ -776,9 +2475,55 @@ impl App {
 
         let exit_status = ExitStatus::from_raw(status);
         if exit_status.stopped_signal().is_some() {
-            self.editor_subprocess = Some(child);
+            self.editor_sessions
+                .insert(slug, EditorSession { child, lang });
         }
 
+        self.reload_preview();
+
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Creates a case with an output and the output it should have had.
+    fn case(output: &str, expected: &str) -> TestCase {
+        TestCase {
+            input: Vec::new(),
+            output: Some(output.to_string()),
+            expected: Some(expected.to_string()),
+        }
+    }
+
+    #[test]
+    fn ignores_whitespace_outside_strings() {
+        assert_eq!(normalize_output("[1, 2,\n 3]\n"), "[1,2,3]");
+        assert_eq!(normalize_output(" true "), "true");
+    }
+
+    #[test]
+    fn keeps_whitespace_inside_strings() {
+        assert_eq!(normalize_output(r#"["a b", "c"]"#), r#"["a b","c"]"#);
+        assert_eq!(
+            normalize_output(r#""say \"hi there\"""#),
+            r#""say \"hi there\"""#
+        );
+        assert_eq!(normalize_output(r#"["a\\", " b"]"#), r#"["a\\"," b"]"#);
+    }
+
+    #[test]
+    fn compares_normalized_outputs() {
+        assert_eq!(case("[1,2]", "[1, 2]").passed(), Some(true));
+        assert_eq!(case(r#""a b""#, r#""ab""#).passed(), Some(false));
+        assert_eq!(case("1", "2").passed(), Some(false));
+
+        let unknown = TestCase {
+            expected: None,
+            ..case("1", "1")
+        };
+        assert_eq!(unknown.passed(), None);
+    }
+}
@@ -0,0 +1,767 @@
+use std::{fs, io, path::PathBuf};
+
+use api::Language;
+use ratatui::{
+    crossterm::event::{KeyCode, KeyEvent},
+    text::Line,
+};
+use unicode_width::UnicodeWidthChar;
+
+use super::{
+    keymap::{
+        self, EX_COMMANDS, ExCommand, FieldKey, INSERT_KEYS, InsertKey, KeyBinding, LINE_COMMAND,
+        Lookup, NORMAL_KEYS, NormalKey, PROMPT_KEYS,
+    },
+    rendering,
+    theme::Theme,
+};
+
+/// The amount of columns a tab is drawn with.
+pub const TAB_WIDTH: usize = 4;
+
+/// The amount of lines moved by half-page jumps.
+const HALF_PAGE: usize = 10;
+
+/// The mode of the built-in editor, following vim's.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum Mode {
+    Normal,
+    Insert,
+    Command,
+    Search,
+}
+
+/// What the app has to do after the editor handled a key.
+pub enum EditorCommand {
+    Nothing,
+    Save,
+    SaveAndClose,
+    Close,
+    /// Gives the focus back to the rest of the editor screen.
+    Blur,
+}
+
+/// The buffer and cursor at some point in time, to undo changes back to it.
+#[derive(Clone)]
+struct Snapshot {
+    lines: Vec<String>,
+    row: usize,
+    col: usize,
+}
+
+/// A small modal text editor, so solutions can be written without leaving leetui.
+pub struct CodeEditor {
+    pub path: PathBuf,
+    pub lang: Language,
+    pub lines: Vec<String>,
+    pub row: usize,
+    /// The cursor column, counted in characters.
+    pub col: usize,
+    pub mode: Mode,
+    /// The text typed after `:` or `/`.
+    pub prompt: String,
+    pub message: Option<String>,
+    pub scroll_row: usize,
+    pub scroll_col: usize,
+    saved: Vec<String>,
+    indent_unit: String,
+    search: Option<String>,
+    /// The first key of a two key command, like `dd` or `gg`.
+    pending: Option<KeyBinding>,
+    /// The lines yanked or deleted last.
+    register: Vec<String>,
+    undo: Vec<Snapshot>,
+    redo: Vec<Snapshot>,
+    /// The buffer highlighted for drawing, dropped whenever the buffer changes.
+    highlighted: Option<Vec<Line<'static>>>,
+}
+
+impl CodeEditor {
+    /// Opens a file in the editor.
+    ///
+    /// # Arguments
+    /// * `path` - The path of the file, which must exist.
+    /// * `lang` - The language of the file, to indent and highlight it.
+    pub fn open(path: PathBuf, lang: Language) -> io::Result<Self> {
+        let text = fs::read_to_string(&path)?;
+        let mut lines: Vec<_> = text.lines().map(str::to_string).collect();
+        if lines.is_empty() {
+            lines.push(String::new());
+        }
+
+        Ok(Self {
+            path,
+            lang,
+            indent_unit: detect_indent(&lines),
+            saved: lines.clone(),
+            lines,
+            row: 0,
+            col: 0,
+            mode: Mode::Normal,
+            prompt: String::new(),
+            message: None,
+            scroll_row: 0,
+            scroll_col: 0,
+            search: None,
+            pending: None,
+            register: Vec::new(),
+            undo: Vec::new(),
+            redo: Vec::new(),
+            highlighted: None,
+        })
+    }
+
+    /// Whether the buffer differs from the file on disk.
+    pub fn is_modified(&self) -> bool {
+        self.lines != self.saved
+    }
+
+    /// Reads the file again after it was written by someone else, keeping the cursor in place.
+    ///
+    /// Buffers with unsaved changes are kept as they are.
+    pub fn reload(&mut self) -> io::Result<()> {
+        if self.is_modified() {
+            self.message = Some("the file changed on disk".into());
+            return Ok(());
+        }
+
+        let text = fs::read_to_string(&self.path)?;
+        let mut lines: Vec<_> = text.lines().map(str::to_string).collect();
+        if lines.is_empty() {
+            lines.push(String::new());
+        }
+
+        if lines != self.lines {
+            self.checkpoint();
+            self.saved = lines.clone();
+            self.lines = lines;
+            self.move_to(self.row, self.col);
+        }
+
+        Ok(())
+    }
+
+    /// Writes the buffer into its file.
+    pub fn save(&mut self) -> io::Result<()> {
+        let mut text = self.lines.join("\n");
+        text.push('\n');
+
+        fs::write(&self.path, text)?;
+        self.saved = self.lines.clone();
+        self.message = Some(format!("\"{}\" written", self.file_name()));
+        Ok(())
+    }
+
+    /// Returns the name of the file being edited.
+    pub fn file_name(&self) -> String {
+        let name = self.path.file_name().unwrap_or_default();
+        name.to_string_lossy().into_owned()
+    }
+
+    /// Returns the whole buffer as text.
+    pub fn text(&self) -> String {
+        self.lines.join("\n")
+    }
+
+    /// Returns the buffer highlighted with the color scheme of a theme,
+    /// highlighting it again only if it changed since the last call.
+    ///
+    /// # Arguments
+    /// * `theme` - The theme whose color scheme is used.
+    pub fn highlighted(&mut self, theme: &Theme) -> &[Line<'static>] {
+        if self.highlighted.is_none() {
+            let lines = rendering::highlight(&self.text(), Some(self.lang), theme);
+            self.highlighted = Some(lines);
+        }
+
+        self.highlighted.as_deref().unwrap_or_default()
+    }
+
+    /// Drops the highlighted buffer, so it's highlighted again with a new theme.
+    pub fn clear_highlight(&mut self) {
+        self.highlighted = None;
+    }
+
+    /// Returns the column the cursor is drawn at, counting tabs and wide characters.
+    pub fn cursor_column(&self) -> usize {
+        display_width(self.lines[self.row].chars().take(self.col))
+    }
+
+    /// Scrolls the view just enough to keep the cursor inside of it.
+    ///
+    /// # Arguments
+    /// * `height` - The amount of rows of the view.
+    /// * `width` - The amount of columns of the view.
+    pub fn scroll_to_cursor(&mut self, height: usize, width: usize) {
+        let height = height.max(1);
+        let width = width.max(1);
+
+        if self.row < self.scroll_row {
+            self.scroll_row = self.row;
+        } else if self.row >= self.scroll_row + height {
+            self.scroll_row = self.row + 1 - height;
+        }
+
+        let column = self.cursor_column();
+        if column < self.scroll_col {
+            self.scroll_col = column;
+        } else if column >= self.scroll_col + width {
+            self.scroll_col = column + 1 - width;
+        }
+    }
+
+    /// Handles a key press.
+    ///
+    /// # Returns
+    /// What the app has to do in response.
+    pub fn handle_key(&mut self, key: KeyEvent) -> EditorCommand {
+        self.message = None;
+
+        match self.mode {
+            Mode::Normal => self.normal_key(key),
+            Mode::Insert => {
+                self.insert_key(key);
+                EditorCommand::Nothing
+            }
+            Mode::Command => self.command_key(key),
+            Mode::Search => {
+                self.search_key(key);
+                EditorCommand::Nothing
+            }
+        }
+    }
+
+    fn normal_key(&mut self, key: KeyEvent) -> EditorCommand {
+        let pending = self.pending.take();
+        let action = match keymap::lookup(NORMAL_KEYS, pending, KeyBinding::from_event(key)) {
+            Lookup::Action(action) => action,
+            Lookup::Pending => {
+                self.pending = Some(KeyBinding::from_event(key));
+                return EditorCommand::Nothing;
+            }
+            Lookup::Unbound => return EditorCommand::Nothing,
+        };
+
+        match action {
+            NormalKey::Left => self.move_to(self.row, self.col.saturating_sub(1)),
+            NormalKey::Down => self.move_to(self.row + 1, self.col),
+            NormalKey::Up => self.move_to(self.row.saturating_sub(1), self.col),
+            NormalKey::Right => self.move_to(self.row, self.col + 1),
+            NormalKey::NextWord => self.next_word(),
+            NormalKey::PreviousWord => self.previous_word(),
+            NormalKey::LineStart => self.col = 0,
+            NormalKey::Indentation => self.col = self.indent_of(self.row),
+            NormalKey::LineEnd => self.move_to(self.row, usize::MAX),
+            NormalKey::FirstLine => self.move_to(0, 0),
+            NormalKey::LastLine => self.move_to(usize::MAX, 0),
+            NormalKey::HalfPageDown => self.move_to(self.row + HALF_PAGE, self.col),
+            NormalKey::HalfPageUp => self.move_to(self.row.saturating_sub(HALF_PAGE), self.col),
+            NormalKey::Insert => self.start_insert(self.col),
+            NormalKey::Append => self.start_insert(self.col + 1),
+            NormalKey::InsertAtIndentation => self.start_insert(self.indent_of(self.row)),
+            NormalKey::AppendAtEnd => self.start_insert(usize::MAX),
+            NormalKey::OpenBelow => self.open_line(self.row + 1),
+            NormalKey::OpenAbove => self.open_line(self.row),
+            NormalKey::DeleteChar => self.delete_char(),
+            NormalKey::DeleteToEnd => self.delete_to_end(),
+            NormalKey::DeleteLine => self.delete_line(),
+            NormalKey::YankLine => {
+                self.register = vec![self.lines[self.row].clone()];
+                self.message = Some("1 line yanked".into());
+            }
+            NormalKey::PasteBelow => self.paste(self.row + 1),
+            NormalKey::PasteAbove => self.paste(self.row),
+            NormalKey::Undo => self.undo(),
+            NormalKey::Redo => self.redo(),
+            NormalKey::Search => {
+                self.prompt.clear();
+                self.mode = Mode::Search;
+            }
+            NormalKey::NextMatch => self.find(true),
+            NormalKey::PreviousMatch => self.find(false),
+            NormalKey::Command => {
+                self.prompt.clear();
+                self.mode = Mode::Command;
+            }
+            NormalKey::Save => return EditorCommand::Save,
+            // Escape only cancels a pending command, like vim.
+            NormalKey::Blur if pending.is_none() => return EditorCommand::Blur,
+            NormalKey::Blur => {}
+        }
+
+        EditorCommand::Nothing
+    }
+
+    fn insert_key(&mut self, key: KeyEvent) {
+        let action = match keymap::lookup(INSERT_KEYS, None, KeyBinding::from_event(key)) {
+            Lookup::Action(action) => action,
+            _ => {
+                if let KeyCode::Char(ch) = key.code {
+                    self.highlighted = None;
+                    self.insert_char(ch);
+                }
+
+                return;
+            }
+        };
+
+        let edits = matches!(
+            action,
+            InsertKey::Indent | InsertKey::BreakLine | InsertKey::Backspace | InsertKey::Delete
+        );
+
+        // Typing doesn't go through `checkpoint`, which forgets the highlighting otherwise.
+        if edits {
+            self.highlighted = None;
+        }
+
+        match action {
+            InsertKey::Normal => {
+                self.mode = Mode::Normal;
+                self.move_to(self.row, self.col.saturating_sub(1));
+            }
+            InsertKey::Indent => {
+                let unit = self.indent_unit.clone();
+                self.insert_str(&unit);
+            }
+            InsertKey::BreakLine => self.break_line(),
+            InsertKey::Backspace => self.backspace(),
+            InsertKey::Delete => {
+                let len = self.line_len(self.row);
+                if self.col < len {
+                    let at = byte_index(&self.lines[self.row], self.col);
+                    self.lines[self.row].remove(at);
+                } else if self.row + 1 < self.lines.len() {
+                    let next = self.lines.remove(self.row + 1);
+                    self.lines[self.row].push_str(&next);
+                }
+            }
+            InsertKey::Left => self.col = self.col.saturating_sub(1),
+            InsertKey::Right => self.col = (self.col + 1).min(self.line_len(self.row)),
+            InsertKey::Up => self.move_to(self.row.saturating_sub(1), self.col),
+            InsertKey::Down => self.move_to(self.row + 1, self.col),
+            InsertKey::LineStart => self.col = 0,
+            InsertKey::LineEnd => self.col = self.line_len(self.row),
+        }
+    }
+
+    /// Handles a key typed in the `:` or `/` prompt.
+    ///
+    /// # Returns
+    /// The text of the prompt if it was confirmed.
+    fn prompt_key(&mut self, key: KeyEvent) -> Option<String> {
+        match keymap::lookup(PROMPT_KEYS, None, KeyBinding::from_event(key)) {
+            Lookup::Action(FieldKey::Confirm) => {
+                self.mode = Mode::Normal;
+                return Some(std::mem::take(&mut self.prompt));
+            }
+            Lookup::Action(FieldKey::Cancel) => self.mode = Mode::Normal,
+            Lookup::Action(FieldKey::DeleteChar) if self.prompt.is_empty() => {
+                self.mode = Mode::Normal
+            }
+            Lookup::Action(FieldKey::DeleteChar) => {
+                self.prompt.pop();
+            }
+            _ => {
+                if let KeyCode::Char(ch) = key.code {
+                    self.prompt.push(ch);
+                }
+            }
+        }
+
+        None
+    }
+
+    fn command_key(&mut self, key: KeyEvent) -> EditorCommand {
+        match self.prompt_key(key) {
+            Some(command) => self.run_command(command.trim()),
+            None => EditorCommand::Nothing,
+        }
+    }
+
+    /// Runs a command typed after `:`.
+    fn run_command(&mut self, command: &str) -> EditorCommand {
+        let name = match command.parse::<usize>() {
+            Ok(_) => LINE_COMMAND,
+            Err(_) => command,
+        };
+
+        let action = EX_COMMANDS
+            .iter()
+            .find(|binding| binding.keys.contains(&name))
+            .map(|binding| binding.action);
+
+        match action {
+            Some(ExCommand::Write) => EditorCommand::Save,
+            Some(ExCommand::WriteQuit) => EditorCommand::SaveAndClose,
+            Some(ExCommand::Quit) if self.is_modified() => {
+                self.message = Some("no write since last change (add ! to override)".into());
+                EditorCommand::Nothing
+            }
+            Some(ExCommand::Quit | ExCommand::ForceQuit) => EditorCommand::Close,
+            Some(ExCommand::GoToLine) => {
+                let line = command.parse::<usize>().unwrap_or_default();
+                self.move_to(line.saturating_sub(1), 0);
+                EditorCommand::Nothing
+            }
+            None => {
+                self.message = Some(format!("not an editor command: {command}"));
+                EditorCommand::Nothing
+            }
+        }
+    }
+
+    fn search_key(&mut self, key: KeyEvent) {
+        let Some(pattern) = self.prompt_key(key) else {
+            return;
+        };
+
+        if !pattern.is_empty() {
+            self.search = Some(pattern);
+        }
+
+        self.find(true);
+    }
+
+    /// Moves the cursor to the next match of the last search.
+    ///
+    /// # Arguments
+    /// * `forward` - Whether to look after the cursor rather than before it.
+    fn find(&mut self, forward: bool) {
+        let Some(pattern) = self.search.clone() else {
+            self.message = Some("no previous search".into());
+            return;
+        };
+
+        let count = self.lines.len();
+        let cursor = byte_index(&self.lines[self.row], self.col);
+
+        // The cursor's line is visited twice, to find matches on both sides of it.
+        for step in 0..=count {
+            let row = match forward {
+                true => (self.row + step) % count,
+                false => (self.row + count * 2 - step) % count,
+            };
+
+            let line = &self.lines[row];
+            let found = match (forward, step) {
+                (true, 0) => line
+                    .match_indices(&pattern)
+                    .find(|(i, _)| *i > cursor)
+                    .map(|(i, _)| i),
+                (false, 0) => line
+                    .match_indices(&pattern)
+                    .filter(|(i, _)| *i < cursor)
+                    .last()
+                    .map(|(i, _)| i),
+                (true, _) => line.find(&pattern),
+                (false, _) => line.rfind(&pattern),
+            };
+
+            if let Some(i) = found {
+                self.row = row;
+                self.col = line[..i].chars().count();
+                return;
+            }
+        }
+
+        self.message = Some(format!("pattern not found: {pattern}"));
+    }
+
+    /// Moves the cursor, keeping it inside the buffer.
+    fn move_to(&mut self, row: usize, col: usize) {
+        self.row = row.min(self.lines.len() - 1);
+
+        let len = self.line_len(self.row);
+        let last = match self.mode {
+            Mode::Insert => len,
+            _ => len.saturating_sub(1),
+        };
+
+        self.col = col.min(last);
+    }
+
+    fn next_word(&mut self) {
+        let chars: Vec<_> = self.lines[self.row].chars().collect();
+        let class = chars.get(self.col).map(|ch| char_class(*ch));
+
+        let mut i = self.col;
+        while i < chars.len() && Some(char_class(chars[i])) == class {
+            i += 1;
+        }
+        while i < chars.len() && chars[i].is_whitespace() {
+            i += 1;
+        }
+
+        match i < chars.len() || self.row + 1 == self.lines.len() {
+            true => self.move_to(self.row, i),
+            false => {
+                self.row += 1;
+                self.col = self.indent_of(self.row);
+            }
+        }
+    }
+
+    fn previous_word(&mut self) {
+        if self.col == 0 {
+            if self.row > 0 {
+                self.move_to(self.row - 1, usize::MAX);
+            }
+            return;
+        }
+
+        let chars: Vec<_> = self.lines[self.row].chars().collect();
+        let mut i = self.col.min(chars.len());
+
+        while i > 0 && chars[i - 1].is_whitespace() {
+            i -= 1;
+        }
+
+        if i > 0 {
+            let class = char_class(chars[i - 1]);
+            while i > 0 && char_class(chars[i - 1]) == class {
+                i -= 1;
+            }
+        }
+
+        self.col = i;
+    }
+
+    fn start_insert(&mut self, col: usize) {
+        self.checkpoint();
+        self.mode = Mode::Insert;
+        self.move_to(self.row, col);
+    }
+
+    /// Inserts an empty line, indented like the current one, and starts typing on it.
+    fn open_line(&mut self, row: usize) {
+        self.checkpoint();
+        self.mode = Mode::Insert;
+
+        let indent = match row > self.row {
+            true => self.indentation_after(self.row, usize::MAX),
+            false => self.leading_whitespace(self.row),
+        };
+
+        self.col = indent.chars().count();
+        self.lines.insert(row, indent);
+        self.row = row;
+    }
+
+    fn insert_char(&mut self, ch: char) {
+        // Closing brackets typed on a blank line are dedented one level.
+        if matches!(ch, '}' | ')' | ']') {
+            let before: String = self.lines[self.row].chars().take(self.col).collect();
+            if before.trim().is_empty() && before.ends_with(&self.indent_unit) {
+                let start = before.len() - self.indent_unit.len();
+                self.lines[self.row].replace_range(start..before.len(), "");
+                self.col -= self.indent_unit.chars().count();
+            }
+        }
+
+        let at = byte_index(&self.lines[self.row], self.col);
+        self.lines[self.row].insert(at, ch);
+        self.col += 1;
+    }
+
+    fn insert_str(&mut self, text: &str) {
+        let at = byte_index(&self.lines[self.row], self.col);
+        self.lines[self.row].insert_str(at, text);
+        self.col += text.chars().count();
+    }
+
+    /// Splits the line at the cursor, indenting the new line.
+    fn break_line(&mut self) {
+        let at = byte_index(&self.lines[self.row], self.col);
+        let indent = self.indentation_after(self.row, self.col);
+        let rest = self.lines[self.row].split_off(at);
+
+        let kept = self.lines[self.row].trim_end().len();
+        self.lines[self.row].truncate(kept);
+        self.col = indent.chars().count();
+        self.lines
+            .insert(self.row + 1, format!("{indent}{}", rest.trim_start()));
+        self.row += 1;
+    }
+
+    fn backspace(&mut self) {
+        if self.col == 0 {
+            if self.row > 0 {
+                let line = self.lines.remove(self.row);
+                self.row -= 1;
+                self.col = self.line_len(self.row);
+                self.lines[self.row].push_str(&line);
+            }
+            return;
+        }
+
+        // Deleting inside the indentation removes a whole level.
+        let before: String = self.lines[self.row].chars().take(self.col).collect();
+        let count = match before.trim().is_empty() && before.ends_with(&self.indent_unit) {
+            true => self.indent_unit.chars().count(),
+            false => 1,
+        };
+
+        let start = byte_index(&self.lines[self.row], self.col - count);
+        self.lines[self.row].replace_range(start..before.len(), "");
+        self.col -= count;
+    }
+
+    fn delete_char(&mut self) {
+        if self.line_len(self.row) == 0 {
+            return;
+        }
+
+        self.checkpoint();
+        let at = byte_index(&self.lines[self.row], self.col);
+        self.lines[self.row].remove(at);
+        self.move_to(self.row, self.col);
+    }
+
+    fn delete_to_end(&mut self) {
+        self.checkpoint();
+        let at = byte_index(&self.lines[self.row], self.col);
+        self.lines[self.row].truncate(at);
+        self.move_to(self.row, self.col);
+    }
+
+    fn delete_line(&mut self) {
+        self.checkpoint();
+        self.register = vec![self.lines.remove(self.row)];
+
+        if self.lines.is_empty() {
+            self.lines.push(String::new());
+        }
+
+        let col = self.indent_of(self.row.min(self.lines.len() - 1));
+        self.move_to(self.row, col);
+    }
+
+    /// Inserts the yanked lines before the given row.
+    fn paste(&mut self, row: usize) {
+        if self.register.is_empty() {
+            return;
+        }
+
+        self.checkpoint();
+        for (i, line) in self.register.iter().enumerate() {
+            self.lines.insert(row + i, line.clone());
+        }
+
+        let col = self.indent_of(row);
+        self.move_to(row, col);
+    }
+
+    /// Saves the state of the buffer so the next change can be undone.
+    fn checkpoint(&mut self) {
+        self.undo.push(self.snapshot());
+        self.redo.clear();
+        self.highlighted = None;
+    }
+
+    fn snapshot(&self) -> Snapshot {
+        Snapshot {
+            lines: self.lines.clone(),
+            row: self.row,
+            col: self.col,
+        }
+    }
+
+    fn restore(&mut self, snapshot: Snapshot) {
+        self.lines = snapshot.lines;
+        self.highlighted = None;
+        self.move_to(snapshot.row, snapshot.col);
+    }
+
+    fn undo(&mut self) {
+        match self.undo.pop() {
+            Some(snapshot) => {
+                self.redo.push(self.snapshot());
+                self.restore(snapshot);
+            }
+            None => self.message = Some("already at oldest change".into()),
+        }
+    }
+
+    fn redo(&mut self) {
+        match self.redo.pop() {
+            Some(snapshot) => {
+                self.undo.push(self.snapshot());
+                self.restore(snapshot);
+            }
+            None => self.message = Some("already at newest change".into()),
+        }
+    }
+
+    /// Returns the amount of characters of a line.
+    fn line_len(&self, row: usize) -> usize {
+        self.lines[row].chars().count()
+    }
+
+    fn leading_whitespace(&self, row: usize) -> String {
+        let line = &self.lines[row];
+        let trimmed = line.trim_start();
+        line[..line.len() - trimmed.len()].to_string()
+    }
+
+    /// Returns the column of the first character of a line that isn't whitespace.
+    fn indent_of(&self, row: usize) -> usize {
+        self.leading_whitespace(row).chars().count()
+    }
+
+    /// Returns the indentation of a line started after a column of another,
+    /// one level deeper if the text before it opens a block.
+    fn indentation_after(&self, row: usize, col: usize) -> String {
+        let mut indent = self.leading_whitespace(row);
+        let before: String = self.lines[row].chars().take(col).collect();
+
+        if before.trim_end().ends_with(['{', '(', '[', ':']) {
+            indent.push_str(&self.indent_unit);
+        }
+
+        indent
+    }
+}
+
+/// Guesses the indentation of a file from its lines, defaulting to four spaces.
+fn detect_indent(lines: &[String]) -> String {
+    if lines.iter().any(|line| line.starts_with('\t')) {
+        return "\t".to_string();
+    }
+
+    let width = lines
+        .iter()
+        .map(|line| line.len() - line.trim_start_matches(' ').len())
+        .filter(|width| *width > 0)
+        .min()
+        .unwrap_or(TAB_WIDTH);
+
+    " ".repeat(width)
+}
+
+/// Returns the byte offset of a character of a line, or its length if it's past the end.
+fn byte_index(line: &str, col: usize) -> usize {
+    line.char_indices()
+        .nth(col)
+        .map(|(i, _)| i)
+        .unwrap_or(line.len())
+}
+
+/// Returns the amount of columns some characters take, with tabs expanded.
+pub fn display_width(chars: impl Iterator<Item = char>) -> usize {
+    chars
+        .map(|ch| match ch {
+            '\t' => TAB_WIDTH,
+            ch => ch.width().unwrap_or_default(),
+        })
+        .sum()
+}
+
+/// Groups characters the way `w` and `b` skip over them.
+fn char_class(ch: char) -> u8 {
+    match ch {
+        ch if ch.is_whitespace() => 0,
+        ch if ch.is_alphanumeric() || ch == '_' => 1,
+        _ => 2,
+    }
+}
@@ -0,0 +1,299 @@
+use std::{
+    env,
+    fmt::{self, Display},
+    fs, io,
+    path::{Path, PathBuf},
+    process::Command,
+};
+
+use api::Language;
+use serde::{Deserialize, Deserializer, de};
+
+use super::{
+    keymap::{Keymap, KeysConfig},
+    rendering,
+    theme::{Theme, ThemeConfig},
+    utils,
+};
+
+/// Every language a default can be picked from.
+const LANGUAGES: &[Language] = &[
+    Language::C,
+    Language::Cpp,
+    Language::Java,
+    Language::Python,
+    Language::Python3,
+    Language::Csharp,
+    Language::JavaScript,
+    Language::Ruby,
+    Language::Swift,
+    Language::Go,
+    Language::Scala,
+    Language::Kotlin,
+    Language::Rust,
+    Language::Php,
+    Language::TypeScript,
+    Language::Racket,
+    Language::Erlang,
+    Language::Elixir,
+    Language::Dart,
+];
+
+/// The most problems LeetCode returns in a single page.
+const MAX_PAGE_SIZE: usize = 100;
+
+/// The user's settings, read from `config.toml`.
+#[derive(Debug, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Config {
+    /// The directory every problem workspace is created in.
+    pub workspace_dir: PathBuf,
+    /// The language selected when opening a problem, if it has a snippet for it.
+    #[serde(deserialize_with = "deserialize_language")]
+    pub default_language: Option<Language>,
+    /// The editor command, used when neither `$VISUAL` nor `$EDITOR` is set.
+    pub editor: Option<String>,
+    /// How many problems are fetched at a time.
+    pub page_size: usize,
+    /// How close to the end of the list the cursor gets before the next page
+    /// is fetched, half a page unless set.
+    pub scroll_threshold: Option<usize>,
+    pub credentials: Credentials,
+    pub features: Features,
+    /// The name of a bundled theme, or a table overriding the colors of one.
+    pub theme: ThemeConfig,
+    pub keys: KeysConfig,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            workspace_dir: utils::default_workspace_dir(),
+            default_language: None,
+            editor: None,
+            page_size: 50,
+            scroll_threshold: None,
+            credentials: Credentials::default(),
+            features: Features::default(),
+            theme: ThemeConfig::default(),
+            keys: KeysConfig::default(),
+        }
+    }
+}
+
+/// Where the LeetCode session cookie and csrf token come from.
+#[derive(Debug, Default, Deserialize)]
+#[serde(tag = "source", rename_all = "lowercase", deny_unknown_fields)]
+pub enum Credentials {
+    /// The `LEETCODE_SESSION` and `CSRF_TOKEN` environment variables.
+    #[default]
+    Env,
+    /// Values written in the configuration itself.
+    Plain { session: String, csrf: String },
+    /// Shell commands printing each value, like `pass show leetcode/session`.
+    Command { session: String, csrf: String },
+}
+
+/// Parts of leetui that can be turned off or on.
+#[derive(Debug, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Features {
+    /// Draw the images of descriptions when the terminal supports it.
+    pub images: bool,
+    /// Open terminal editors in a tmux, zellij or kitty pane next to leetui.
+    pub editor_panes: bool,
+    /// Start new solutions with the description as a comment.
+    pub description_header: bool,
+    /// Click and scroll with the mouse, at the cost of the terminal's own text selection.
+    pub mouse: bool,
+}
+
+impl Default for Features {
+    fn default() -> Self {
+        Self {
+            images: true,
+            editor_panes: true,
+            description_header: false,
+            mouse: true,
+        }
+    }
+}
+
+/// The error type for loading the configuration.
+#[derive(Debug)]
+pub enum ConfigError {
+    Read(PathBuf, io::Error),
+    Parse(PathBuf, String),
+    Invalid(PathBuf, String),
+    Credentials(String),
+}
+use ConfigError::*;
+
+impl std::error::Error for ConfigError {}
+
+impl Display for ConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Read(path, e) => write!(f, "couldn't read {}: {e}", path.display()),
+            Parse(path, e) => write!(f, "invalid configuration in {}:\n{e}", path.display()),
+            Invalid(path, e) => write!(f, "invalid configuration in {}: {e}", path.display()),
+            Credentials(e) => write!(f, "credentials error: {e}"),
+        }
+    }
+}
+
+impl Config {
+    /// Returns the path of the configuration file when none is given.
+    pub fn default_path() -> PathBuf {
+        utils::config_dir().join("config.toml")
+    }
+
+    /// Loads and validates the configuration.
+    ///
+    /// # Arguments
+    /// * `path` - The file given with `--config`, if any.
+    ///
+    /// # Returns
+    /// The configuration, which is the default one when no file was given and
+    /// the default file doesn't exist.
+    pub fn load(path: Option<&Path>) -> Result<Self, ConfigError> {
+        let (path, required) = match path {
+            Some(path) => (path.to_path_buf(), true),
+            None => (Self::default_path(), false),
+        };
+
+        let contents = match fs::read_to_string(&path) {
+            Ok(contents) => contents,
+            Err(e) if e.kind() == io::ErrorKind::NotFound && !required => {
+                return Ok(Self::default());
+            }
+            Err(e) => return Err(Read(path, e)),
+        };
+
+        let mut config: Self =
+            toml::from_str(&contents).map_err(|e| Parse(path.clone(), e.to_string()))?;
+
+        config.workspace_dir = expand_home(&config.workspace_dir);
+        config.validate().map_err(|e| Invalid(path, e))?;
+        Ok(config)
+    }
+
+    /// Returns how close to the end of the list the cursor gets before the
+    /// next page is fetched.
+    pub fn scroll_threshold(&self) -> usize {
+        self.scroll_threshold.unwrap_or(self.page_size / 2)
+    }
+
+    /// Checks the values that parse but make no sense.
+    fn validate(&self) -> Result<(), String> {
+        if !self.workspace_dir.is_absolute() {
+            return Err(format!(
+                "workspace_dir must be an absolute path, got {}",
+                self.workspace_dir.display()
+            ));
+        }
+
+        if self.page_size == 0 || self.page_size > MAX_PAGE_SIZE {
+            return Err(format!(
+                "page_size must be between 1 and {MAX_PAGE_SIZE}, got {}",
+                self.page_size
+            ));
+        }
+
+        if let Some(threshold) = self.scroll_threshold
+            && threshold >= self.page_size
+        {
+            return Err(format!(
+                "scroll_threshold must be less than page_size ({}), got {threshold}",
+                self.page_size
+            ));
+        }
+
+        if let Some(editor) = &self.editor {
+            match shell_words::split(editor) {
+                Ok(words) if !words.is_empty() => {}
+                Ok(_) => return Err("editor can't be empty".into()),
+                Err(e) => return Err(format!("editor {editor:?} can't be parsed: {e}")),
+            }
+        }
+
+        let theme = Theme::from_config(&self.theme)?;
+        if let Some(ref syntax) = theme.syntax
+            && !rendering::syntax_theme_exists(syntax)
+        {
+            return Err(format!("unknown syntax theme {syntax:?}"));
+        }
+
+        Keymap::from_config(&self.keys)?;
+
+        Ok(())
+    }
+}
+
+impl Credentials {
+    /// Retrieves the LeetCode variables needed to create the `LeetCodeClient` api.
+    ///
+    /// # Returns
+    /// The session cookie and csrf token, or an error if they couldn't be found.
+    pub fn resolve(&self) -> Result<(String, String), ConfigError> {
+        match self {
+            Self::Env => {
+                let session = env::var("LEETCODE_SESSION")
+                    .map_err(|_| Credentials("LEETCODE_SESSION is not defined".into()))?;
+                let csrf = env::var("CSRF_TOKEN")
+                    .map_err(|_| Credentials("CSRF_TOKEN is not defined".into()))?;
+                Ok((session, csrf))
+            }
+            Self::Plain { session, csrf } => Ok((session.clone(), csrf.clone())),
+            Self::Command { session, csrf } => Ok((run_command(session)?, run_command(csrf)?)),
+        }
+    }
+}
+
+/// Runs a shell command and returns what it printed, without the trailing newline.
+fn run_command(command: &str) -> Result<String, ConfigError> {
+    let output = Command::new("sh")
+        .args(["-c", command])
+        .output()
+        .map_err(|e| Credentials(format!("couldn't run {command:?}: {e}")))?;
+
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        return Err(Credentials(format!(
+            "{command:?} exited with {}: {}",
+            output.status,
+            stderr.trim()
+        )));
+    }
+
+    let value = String::from_utf8_lossy(&output.stdout).trim().to_string();
+    match value.is_empty() {
+        true => Err(Credentials(format!("{command:?} printed nothing"))),
+        false => Ok(value),
+    }
+}
+
+/// Replaces a leading `~` with the home directory.
+fn expand_home(path: &Path) -> PathBuf {
+    match path.strip_prefix("~") {
+        Ok(rest) => env::home_dir().unwrap_or_default().join(rest),
+        Err(_) => path.to_path_buf(),
+    }
+}
+
+/// Reads a language by its name or extension, ignoring case, like `rust`, `C++` or `py`.
+fn deserialize_language<'de, D>(deserializer: D) -> Result<Option<Language>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let name = String::deserialize(deserializer)?;
+
+    LANGUAGES
+        .iter()
+        .find(|lang| lang.to_string().eq_ignore_ascii_case(&name))
+        .copied()
+        .or_else(|| Language::from_ext(&name.to_lowercase()))
+        .filter(|lang| *lang != Language::Unknown)
+        .map(Some)
+        .ok_or_else(|| de::Error::custom(format!("unknown language {name:?}")))
+}
@@ -0,0 +1,313 @@
+use std::{
+    env, fs, io,
+    path::{Path, PathBuf},
+    process::{Command, Stdio},
+    time::{Duration, Instant},
+};
+
+/// The editor used when nothing else is configured.
+const FALLBACK: &str = "vi";
+
+/// How long a new pane has to start its editor before it's considered closed.
+const PANE_STARTUP: Duration = Duration::from_secs(2);
+
+/// The editors that open their own window, along with the flag that makes
+/// them wait until the file is closed.
+const GUI_EDITORS: &[(&str, Option<&str>)] = &[
+    ("code", Some("--wait")),
+    ("code-insiders", Some("--wait")),
+    ("codium", Some("--wait")),
+    ("cursor", Some("--wait")),
+    ("subl", Some("--wait")),
+    ("zed", Some("--wait")),
+    ("gvim", Some("-f")),
+    ("mvim", Some("-f")),
+    ("gedit", None),
+    ("kate", None),
+];
+
+/// An external editor command, split shell-style into a program and its arguments.
+pub struct ExternalEditor {
+    program: String,
+    args: Vec<String>,
+}
+
+impl ExternalEditor {
+    /// Finds the editor to open solutions with, trying `$VISUAL`, `$EDITOR`,
+    /// the configured one and `vi`, in that order.
+    ///
+    /// # Arguments
+    /// * `configured` - The editor command from the configuration, if any.
+    ///
+    /// # Returns
+    /// The first editor that could be parsed, or `None` if there's none and
+    /// `vi` isn't installed either.
+    pub fn resolve(configured: Option<&str>) -> Option<Self> {
+        let visual = env::var("VISUAL").ok();
+        let editor = env::var("EDITOR").ok();
+
+        [visual.as_deref(), editor.as_deref(), configured]
+            .into_iter()
+            .flatten()
+            .find_map(Self::parse)
+            .or_else(|| find_program(FALLBACK).and_then(|_| Self::parse(FALLBACK)))
+    }
+
+    /// Parses an editor command like `code --wait` or `nvim -u ~/x.vim`.
+    fn parse(command: &str) -> Option<Self> {
+        let mut words = shell_words::split(command).ok()?.into_iter();
+        let program = words.next()?;
+
+        Some(Self {
+            program,
+            args: words.collect(),
+        })
+    }
+
+    /// Returns the name of the program, without its directory.
+    fn name(&self) -> &str {
+        Path::new(&self.program)
+            .file_name()
+            .and_then(|name| name.to_str())
+            .unwrap_or(&self.program)
+    }
+
+    /// Whether the editor opens its own window instead of using the terminal.
+    pub fn is_gui(&self) -> bool {
+        GUI_EDITORS.iter().any(|(name, _)| *name == self.name())
+    }
+
+    /// Builds the command that opens a file with the cursor at some position.
+    ///
+    /// # Arguments
+    /// * `file` - The file to open.
+    /// * `line` - The line to jump to, starting at 1.
+    /// * `column` - The column to jump to, starting at 1.
+    pub fn command(&self, file: &Path, line: usize, column: usize) -> Command {
+        let argv = self.argv(file, line, column);
+        let mut cmd = Command::new(&argv[0]);
+        cmd.args(&argv[1..]);
+        cmd
+    }
+
+    /// Returns the program and every argument needed to open a file with the
+    /// cursor at some position.
+    ///
+    /// # Arguments
+    /// * `file` - The file to open.
+    /// * `line` - The line to jump to, starting at 1.
+    /// * `column` - The column to jump to, starting at 1.
+    pub fn argv(&self, file: &Path, line: usize, column: usize) -> Vec<String> {
+        let mut argv = vec![self.program.clone()];
+        argv.extend(self.args.iter().cloned());
+
+        let wait_flag = GUI_EDITORS
+            .iter()
+            .find(|(name, _)| *name == self.name())
+            .and_then(|(_, flag)| *flag);
+
+        if let Some(flag) = wait_flag
+            && !self.args.iter().any(|arg| arg == flag)
+        {
+            argv.push(flag.to_string());
+        }
+
+        let file = file.display();
+        match self.name() {
+            "vim" | "nvim" | "gvim" | "mvim" => {
+                argv.push(format!("+call cursor({line}, {column})"));
+                argv.push(file.to_string());
+            }
+            "vi" | "nano" => {
+                argv.push(format!("+{line}"));
+                argv.push(file.to_string());
+            }
+            "emacs" | "emacsclient" | "kak" => {
+                argv.push(format!("+{line}:{column}"));
+                argv.push(file.to_string());
+            }
+            "hx" | "helix" => argv.push(format!("{file}:{line}:{column}")),
+            "code" | "code-insiders" | "codium" | "cursor" => {
+                argv.push("--goto".to_string());
+                argv.push(format!("{file}:{line}:{column}"));
+            }
+            _ => argv.push(file.to_string()),
+        }
+
+        argv
+    }
+}
+
+/// A terminal multiplexer or terminal leetui runs inside of, which can
+/// show the editor next to it instead of in its place.
+#[derive(Clone, Copy)]
+pub enum Multiplexer {
+    Tmux,
+    Zellij,
+    Kitty,
+}
+
+impl Multiplexer {
+    /// Detects the multiplexer leetui runs in from the variables each one sets.
+    pub fn detect() -> Option<Self> {
+        if env::var_os("TMUX").is_some() {
+            Some(Self::Tmux)
+        } else if env::var_os("ZELLIJ").is_some() {
+            Some(Self::Zellij)
+        } else if env::var_os("KITTY_WINDOW_ID").is_some() {
+            Some(Self::Kitty)
+        } else {
+            None
+        }
+    }
+
+    /// Runs a command in a new pane to the right of leetui, or a new window for kitty.
+    ///
+    /// Kitty only allows this when remote control is enabled.
+    ///
+    /// # Arguments
+    /// * `argv` - The program to run and its arguments.
+    /// * `dir` - The working directory of the command.
+    /// * `pid_file` - Where the pane writes the id of its process, to tell
+    ///   whether it's still open.
+    ///
+    /// # Returns
+    /// The pane the command runs in.
+    pub fn open(self, argv: &[String], dir: &Path, pid_file: PathBuf) -> io::Result<EditorPane> {
+        let mut cmd = match self {
+            Self::Tmux => {
+                let mut cmd = Command::new("tmux");
+                cmd.args(["split-window", "-h", "-P", "-F", "#{pane_id}", "-c"])
+                    .arg(dir);
+                cmd
+            }
+            Self::Zellij => {
+                let mut cmd = Command::new("zellij");
+                cmd.args(["run", "--close-on-exit", "--direction", "right", "--cwd"])
+                    .arg(dir)
+                    .arg("--");
+                cmd
+            }
+            Self::Kitty => {
+                let mut cmd = Command::new("kitty");
+                cmd.args(["@", "launch", "--type=window", "--cwd"]).arg(dir);
+                cmd
+            }
+        };
+
+        // The shell writes its pid, then becomes the editor.
+        let _ = fs::remove_file(&pid_file);
+        let output = cmd
+            .args(["sh", "-c", "echo $$ > \"$0\"; exec \"$@\""])
+            .arg(&pid_file)
+            .args(argv)
+            .stdin(Stdio::null())
+            .stderr(Stdio::null())
+            .output()?;
+
+        if !output.status.success() {
+            let program = cmd.get_program().to_string_lossy();
+            let status = output.status;
+            return Err(io::Error::other(format!("{program} exited with {status}")));
+        }
+
+        // tmux and kitty print the id of the new pane, zellij doesn't.
+        let id = String::from_utf8_lossy(&output.stdout).trim().to_string();
+
+        Ok(EditorPane {
+            mux: self,
+            id: (!id.is_empty()).then_some(id),
+            pid_file,
+            opened: Instant::now(),
+        })
+    }
+}
+
+/// An editor running in a pane next to leetui.
+pub struct EditorPane {
+    mux: Multiplexer,
+    /// The id the multiplexer knows the pane by, if it reported one.
+    id: Option<String>,
+    pid_file: PathBuf,
+    opened: Instant,
+}
+
+impl EditorPane {
+    /// Whether the editor in the pane is still running.
+    pub fn is_alive(&self) -> bool {
+        let pid = fs::read_to_string(&self.pid_file)
+            .ok()
+            .and_then(|pid| pid.trim().parse::<i32>().ok());
+
+        match pid {
+            Some(pid) => unsafe { libc::kill(pid, 0) == 0 },
+            None => self.opened.elapsed() < PANE_STARTUP,
+        }
+    }
+
+    /// Moves the focus to the pane.
+    ///
+    /// # Returns
+    /// An error if the multiplexer can't focus a pane by its id, like zellij.
+    pub fn focus(&self) -> io::Result<()> {
+        let mut cmd = match (self.mux, &self.id) {
+            (Multiplexer::Tmux, Some(id)) => {
+                let mut cmd = Command::new("tmux");
+                cmd.args(["select-pane", "-t", id]);
+                cmd
+            }
+            (Multiplexer::Kitty, Some(id)) => {
+                let mut cmd = Command::new("kitty");
+                cmd.args(["@", "focus-window", "--match"])
+                    .arg(format!("id:{id}"));
+                cmd
+            }
+            _ => {
+                return Err(io::Error::other(
+                    "the solution is already open in another pane",
+                ));
+            }
+        };
+
+        let status = cmd
+            .stdin(Stdio::null())
+            .stdout(Stdio::null())
+            .stderr(Stdio::null())
+            .status()?;
+
+        match status.success() {
+            true => Ok(()),
+            false => {
+                let program = cmd.get_program().to_string_lossy();
+                Err(io::Error::other(format!("{program} exited with {status}")))
+            }
+        }
+    }
+}
+
+impl Drop for EditorPane {
+    fn drop(&mut self) {
+        let _ = fs::remove_file(&self.pid_file);
+    }
+}
+
+/// Looks for a program in the directories of `$PATH`.
+fn find_program(name: &str) -> Option<PathBuf> {
+    let paths = env::var_os("PATH")?;
+    env::split_paths(&paths)
+        .map(|dir| dir.join(name))
+        .find(|path| path.is_file())
+}
+
+/// Finds where the cursor should start in a solution, which is the first
+/// blank line inside a block, like the body of the function to write.
+///
+/// # Returns
+/// The line and column, both starting at 1.
+pub fn jump_target(code: &str) -> (usize, usize) {
+    code.lines()
+        .enumerate()
+        .find(|(_, line)| !line.is_empty() && line.trim().is_empty())
+        .map(|(i, line)| (i + 1, line.chars().count() + 1))
+        .unwrap_or((1, 1))
+}
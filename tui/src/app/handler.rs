@@ -1,40 +1,46 @@
-use std::time::Duration;
+use std::{sync::Arc, time::Duration};
 
-use api::LeetCodeClient;
-use ratatui::crossterm::event::{self, Event};
+use api::{
+    Difficulty, LeetCodeClient, LeetCodeErr, Question, SubmissionCheckResponse, SubmissionState,
+    TestCasesCheckResponse,
+};
+use image::DynamicImage;
+use ratatui::crossterm::event::{Event, MouseButton, MouseEventKind};
 use tokio::{
     sync::mpsc::{Receiver, Sender},
-    time::Interval,
+    time,
 };
 
-use crate::app::Action;
+use crate::app::{Action, images};
 
-/// Creates the keyboard listener future.
-///
-/// # Arguments
-/// * `tx` - A sending end to send the key events to the application.
-pub async fn spawn_keyboard(tx: Sender<Action>) {
-    while !tx.is_closed() {
-        if event::poll(Duration::from_millis(8)).unwrap_or_default() {
-            if let Event::Key(key_event) = event::read().unwrap() {
-                let _ = tx.send(Action::Key(key_event)).await;
-            } else {
-                let _ = tx.send(Action::Other).await;
-            }
-        }
-    }
-}
+/// The time to wait in between checks of a run or submission.
+const CHECK_INTERVAL: Duration = Duration::from_millis(800);
 
-/// Creates the ticker listener future.
+/// The number of checks before giving up on a run or submission, about a minute.
+const MAX_CHECKS: usize = 75;
+
+/// Turns a terminal event into the action the application handles.
 ///
 /// # Arguments
-/// * `tx` - A sending end to send the tick events to the application.
-/// * `interval` - The interval to sleep in between ticks.
-pub async fn spawn_ticker(tx: Sender<Action>, mut interval: Interval) {
-    loop {
-        interval.tick().await;
-        let _ = tx.try_send(Action::Tick);
-    }
+/// * `event` - The event read from the terminal.
+///
+/// # Returns
+/// The action, or `None` for events that don't change anything on screen.
+pub fn event_action(event: Event) -> Option<Action> {
+    let action = match event {
+        Event::Key(key_event) => Action::Key(key_event),
+        // Moving the pointer reports an event per cell, which would redraw for nothing.
+        Event::Mouse(mouse_event) => match mouse_event.kind {
+            MouseEventKind::Down(MouseButton::Left)
+            | MouseEventKind::ScrollDown
+            | MouseEventKind::ScrollUp => Action::Mouse(mouse_event),
+            _ => return None,
+        },
+        Event::Resize(..) => Action::Resize,
+        _ => Action::Other,
+    };
+
+    Some(action)
 }
 
 /// The variants of requests the application can make to the client listener.
@@ -47,15 +53,38 @@ pub enum ClientRequest {
         skip: usize,
         limit: usize,
         search: Option<String>,
+        difficulty: Option<Difficulty>,
     },
     FetchDailyChallenge,
     FetchQuestion {
         slug: String,
     },
+    FetchQuestionById {
+        id: String,
+    },
+    RunTests {
+        slug: String,
+        question_id: String,
+        lang: String,
+        code: String,
+        test_cases: String,
+    },
+    SubmitCode {
+        slug: String,
+        question_id: String,
+        lang: String,
+        code: String,
+    },
+    FetchImage {
+        url: String,
+    },
 }
 
 /// Creates the client listener future.
 ///
+/// Runs and submissions are judged in tasks of their own, since waiting for
+/// LeetCode would otherwise hold up every other request.
+///
 /// # Arguments
 /// * `tx` - A sending end to send the leetcode api responses to the application.
 /// * `rx` - A receiving end to receive the application requests.
@@ -65,6 +94,8 @@ pub async fn spawn_client(
     mut rx: Receiver<ClientRequest>,
     client: LeetCodeClient,
 ) {
+    let client = Arc::new(client);
+
     while let Some(req) = rx.recv().await {
         let result = match req {
             ClientRequest::FetchUserStatus => {
@@ -78,16 +109,18 @@ pub async fn spawn_client(
                 skip,
                 limit,
                 search: None,
+                difficulty,
             } => client
-                .get_problem_list(skip, limit)
+                .get_problem_list(skip, limit, difficulty)
                 .await
                 .map(|p| Action::ProblemListLoaded(p.questions)),
             ClientRequest::FetchProblems {
                 skip,
                 limit,
                 search: Some(keywords),
+                difficulty,
             } => client
-                .search_problem(skip, limit, &keywords)
+                .search_problem(skip, limit, &keywords, difficulty)
                 .await
                 .map(|p| Action::ProblemListLoaded(p.questions)),
             ClientRequest::FetchDailyChallenge => client
@@ -97,15 +130,178 @@ pub async fn spawn_client(
             ClientRequest::FetchQuestion { slug } => {
                 client.get_problem(&slug).await.map(Action::QuestionLoaded)
             }
-        };
-
-        match result {
-            Ok(action) => {
-                let _ = tx.send(action).await;
+            ClientRequest::FetchQuestionById { id } => fetch_question_by_id(&client, &id)
+                .await
+                .map(Action::QuestionLoaded),
+            ClientRequest::RunTests {
+                slug,
+                question_id,
+                lang,
+                code,
+                test_cases,
+            } => {
+                let (tx, client) = (tx.clone(), client.clone());
+                tokio::spawn(async move {
+                    let result = run_tests(&client, &slug, &question_id, &lang, &code, &test_cases)
+                        .await
+                        .map(Action::TestsChecked);
+                    send_result(&tx, result).await;
+                });
+                continue;
+            }
+            ClientRequest::SubmitCode {
+                slug,
+                question_id,
+                lang,
+                code,
+            } => {
+                let (tx, client) = (tx.clone(), client.clone());
+                tokio::spawn(async move {
+                    let result = submit_code(&client, &slug, &question_id, &lang, &code)
+                        .await
+                        .map(Action::SubmissionChecked);
+                    send_result(&tx, result).await;
+                });
+                continue;
             }
-            Err(e) => {
-                let _ = tx.send(Action::NetworkError(e.to_string())).await;
+            ClientRequest::FetchImage { url } => {
+                let image = fetch_image(&client, &url).await;
+                Ok(Action::ImageLoaded(url, image))
             }
+        };
+
+        send_result(&tx, result).await;
+    }
+}
+
+/// Sends the outcome of a request to the application.
+///
+/// # Arguments
+/// * `tx` - A sending end to send the leetcode api responses to the application.
+/// * `result` - The action the request resulted in, or its error.
+async fn send_result(tx: &Sender<Action>, result: api::Result<Action>) {
+    let action = match result {
+        Ok(action) => action,
+        Err(e) => Action::NetworkError(e.to_string()),
+    };
+
+    let _ = tx.send(action).await;
+}
+
+/// Fetches a question by the id LeetCode shows next to its title.
+///
+/// # Arguments
+/// * `client` - The LeetCode api abstraction.
+/// * `id` - The frontend id of the question, like `1` for Two Sum.
+async fn fetch_question_by_id(client: &LeetCodeClient, id: &str) -> api::Result<Question> {
+    let list = client.search_problem(0, 50, id, None).await?;
+    let slug = list
+        .questions
+        .into_iter()
+        .find(|problem| problem.frontend_question_id == id)
+        .map(|problem| problem.title_slug)
+        .ok_or_else(|| LeetCodeErr::Api(format!("no problem has the id {id}")))?;
+
+    client.get_problem(&slug).await
+}
+
+/// Runs the test cases and waits for LeetCode to finish judging them.
+///
+/// # Arguments
+/// * `client` - The LeetCode api abstraction.
+/// * `slug` - The slug for the problem being solved.
+/// * `question_id` - The id of the question.
+/// * `lang` - The language slug of the code.
+/// * `code` - The code to run.
+/// * `test_cases` - The test cases, in LeetCode's newline-separated format.
+async fn run_tests(
+    client: &LeetCodeClient,
+    slug: &str,
+    question_id: &str,
+    lang: &str,
+    code: &str,
+    test_cases: &str,
+) -> api::Result<TestCasesCheckResponse> {
+    let interpret_id = client
+        .run_tests(slug, question_id, lang, code, test_cases)
+        .await?;
+
+    for _ in 0..MAX_CHECKS {
+        time::sleep(CHECK_INTERVAL).await;
+        let res = client.check_test_cases(&interpret_id).await?;
+        match res.state {
+            SubmissionState::Success => return Ok(res),
+            SubmissionState::Unknown => return Err(unknown_state("run")),
+            SubmissionState::Pending | SubmissionState::Started => {}
+        }
+    }
+
+    Err(timed_out("run"))
+}
+
+/// Submits the code and waits for LeetCode to finish judging it.
+///
+/// # Arguments
+/// * `client` - The LeetCode api abstraction.
+/// * `slug` - The slug for the problem being solved.
+/// * `question_id` - The id of the question.
+/// * `lang` - The language slug of the code.
+/// * `code` - The code to submit.
+async fn submit_code(
+    client: &LeetCodeClient,
+    slug: &str,
+    question_id: &str,
+    lang: &str,
+    code: &str,
+) -> api::Result<SubmissionCheckResponse> {
+    let submission_id = client.submit_code(slug, question_id, lang, code).await?;
+
+    for _ in 0..MAX_CHECKS {
+        time::sleep(CHECK_INTERVAL).await;
+        let res = client.check_submission(submission_id).await?;
+        match res.state {
+            SubmissionState::Success => return Ok(res),
+            SubmissionState::Unknown => return Err(unknown_state("submission")),
+            SubmissionState::Pending | SubmissionState::Started => {}
         }
     }
+
+    Err(timed_out("submission"))
+}
+
+/// The error for a judgement LeetCode reports in a state that isn't known.
+///
+/// # Arguments
+/// * `what` - What was being judged, like `run`.
+fn unknown_state(what: &str) -> LeetCodeErr {
+    LeetCodeErr::Api(format!("LeetCode reported an unknown state for the {what}"))
+}
+
+/// The error for a judgement that didn't finish after `MAX_CHECKS` checks.
+///
+/// # Arguments
+/// * `what` - What was being judged, like `run`.
+fn timed_out(what: &str) -> LeetCodeErr {
+    LeetCodeErr::Api(format!("LeetCode took too long to judge the {what}"))
+}
+
+/// Loads an image of a description, downloading it only if it isn't cached.
+///
+/// # Arguments
+/// * `client` - The LeetCode api abstraction.
+/// * `url` - The url of the image.
+///
+/// # Returns
+/// The decoded image, or `None` if it couldn't be fetched or isn't supported.
+async fn fetch_image(client: &LeetCodeClient, url: &str) -> Option<DynamicImage> {
+    let bytes = match images::read_cached(url) {
+        Some(bytes) => bytes,
+        None => {
+            let bytes = client.get_image(url).await.ok()?;
+            let _ = images::write_cached(url, &bytes);
+            bytes
+        }
+    };
+
+    image::load_from_memory(&bytes).ok()
 }
@@ -0,0 +1,170 @@
+use std::{collections::HashMap, fs, io, path::PathBuf};
+
+use image::DynamicImage;
+use ratatui::layout::Size;
+use ratatui_image::{
+    Resize,
+    picker::{Picker, ProtocolType},
+    protocol::Protocol,
+};
+
+use super::utils;
+
+/// The most rows an image may take in the description.
+const MAX_ROWS: u16 = 20;
+
+/// The state of an image referenced by the description.
+enum Entry {
+    Loading,
+    Loaded {
+        image: DynamicImage,
+        protocol: Option<Protocol>,
+    },
+    Failed,
+}
+
+/// The images of the description, ready to be drawn with the terminal's graphics protocol.
+pub struct Images {
+    picker: Option<Picker>,
+    entries: HashMap<String, Entry>,
+}
+
+impl Images {
+    /// Creates a new `Images` store.
+    ///
+    /// # Arguments
+    /// * `picker` - The graphics protocol of the terminal, if it has one.
+    pub fn new(picker: Option<Picker>) -> Self {
+        Self {
+            picker,
+            entries: HashMap::new(),
+        }
+    }
+
+    /// Queries the terminal for a graphics protocol like kitty's, sixel or iTerm2's.
+    ///
+    /// This must run after entering the alternate screen but before reading events.
+    ///
+    /// # Returns
+    /// The picker to draw images with, or `None` if the terminal can't draw them.
+    pub fn query_picker() -> Option<Picker> {
+        Picker::from_query_stdio()
+            .ok()
+            .filter(|picker| picker.protocol_type() != ProtocolType::Halfblocks)
+    }
+
+    /// Whether the terminal can draw images inline.
+    pub fn supported(&self) -> bool {
+        self.picker.is_some()
+    }
+
+    /// Marks an image as being fetched.
+    ///
+    /// # Returns
+    /// Whether the image is new and has to be fetched.
+    pub fn start_loading(&mut self, url: &str) -> bool {
+        if !self.supported() || self.entries.contains_key(url) {
+            return false;
+        }
+
+        self.entries.insert(url.to_string(), Entry::Loading);
+        true
+    }
+
+    /// Stores a fetched image.
+    ///
+    /// # Arguments
+    /// * `url` - The url of the image.
+    /// * `image` - The decoded image, or `None` if it couldn't be fetched.
+    pub fn finish_loading(&mut self, url: String, image: Option<DynamicImage>) {
+        let entry = match image {
+            Some(image) => Entry::Loaded {
+                image,
+                protocol: None,
+            },
+            None => Entry::Failed,
+        };
+
+        self.entries.insert(url, entry);
+    }
+
+    /// Returns the amount of cells an image takes when drawn.
+    ///
+    /// # Arguments
+    /// * `url` - The url of the image.
+    /// * `max_width` - The most columns the image may take.
+    ///
+    /// # Returns
+    /// The size of the image, or `None` if it can't be drawn.
+    pub fn size(&self, url: &str, max_width: u16) -> Option<Size> {
+        let picker = self.picker.as_ref()?;
+        let Some(Entry::Loaded { image, .. }) = self.entries.get(url) else {
+            return None;
+        };
+
+        let available = Size::new(max_width, MAX_ROWS);
+        let size = Resize::Fit(None).size_for(image, picker.font_size(), available);
+
+        (size.width > 0 && size.height > 0).then_some(size)
+    }
+
+    /// Returns the image encoded for the terminal at the given size, encoding it if needed.
+    pub fn protocol(&mut self, url: &str, size: Size) -> Option<&Protocol> {
+        let picker = self.picker.as_ref()?;
+        let Some(Entry::Loaded { image, protocol }) = self.entries.get_mut(url) else {
+            return None;
+        };
+
+        if protocol.as_ref().is_none_or(|p| p.size() != size) {
+            *protocol = picker
+                .new_protocol(image.clone(), size, Resize::Fit(None))
+                .ok();
+        }
+
+        protocol.as_ref()
+    }
+}
+
+/// Returns the path where a downloaded image is cached.
+///
+/// # Arguments
+/// * `url` - The url of the image.
+pub fn cache_path(url: &str) -> PathBuf {
+    let path = url.split("://").last().unwrap_or(url);
+    let name: String = path
+        .chars()
+        .map(|ch| match ch {
+            'a'..='z' | 'A'..='Z' | '0'..='9' | '.' | '-' | '_' => ch,
+            _ => '_',
+        })
+        .collect();
+
+    utils::cache_dir().join("images").join(name)
+}
+
+/// Reads an image from the cache.
+///
+/// # Returns
+/// The bytes of the image, or `None` if it wasn't downloaded yet.
+pub fn read_cached(url: &str) -> Option<Vec<u8>> {
+    fs::read(cache_path(url)).ok()
+}
+
+/// Saves a downloaded image into the cache.
+pub fn write_cached(url: &str, bytes: &[u8]) -> io::Result<()> {
+    let path = cache_path(url);
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)?;
+    }
+
+    fs::write(path, bytes)
+}
+
+/// Opens an image with the system's default viewer, preferring the cached copy.
+pub fn open(url: &str) -> io::Result<()> {
+    let path = cache_path(url);
+    match path.exists() {
+        true => open::that_detached(path),
+        false => open::that_detached(url),
+    }
+}
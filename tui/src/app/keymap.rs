@@ -0,0 +1,1394 @@
+use std::{
+    collections::HashMap,
+    fmt::{self, Display},
+    str::FromStr,
+};
+
+use ratatui::crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use serde::{Deserialize, Deserializer, de};
+
+/// Something a key can be bound to, independent of the key itself.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Command {
+    Quit,
+    Back,
+    Up,
+    Down,
+    Left,
+    Right,
+    PageUp,
+    PageDown,
+    Select,
+    Search,
+    Daily,
+    Sessions,
+    Terminate,
+    TerminateAll,
+    OpenEditor,
+    CodeEditor,
+    RunTests,
+    Submit,
+    TestCases,
+    Language,
+    Files,
+    NewSolution,
+    TogglePreview,
+    PreviewUp,
+    PreviewDown,
+    OpenImage,
+    AddCase,
+    DeleteCase,
+    ResetCases,
+    Palette,
+    Help,
+}
+
+impl Command {
+    /// Returns the name of the command, as written in the configuration.
+    pub fn name(self) -> &'static str {
+        match self {
+            Command::Quit => "quit",
+            Command::Back => "back",
+            Command::Up => "up",
+            Command::Down => "down",
+            Command::Left => "left",
+            Command::Right => "right",
+            Command::PageUp => "page_up",
+            Command::PageDown => "page_down",
+            Command::Select => "select",
+            Command::Search => "search",
+            Command::Daily => "daily",
+            Command::Sessions => "sessions",
+            Command::Terminate => "terminate",
+            Command::TerminateAll => "terminate_all",
+            Command::OpenEditor => "open_editor",
+            Command::CodeEditor => "code_editor",
+            Command::RunTests => "run_tests",
+            Command::Submit => "submit",
+            Command::TestCases => "test_cases",
+            Command::Language => "language",
+            Command::Files => "files",
+            Command::NewSolution => "new_solution",
+            Command::TogglePreview => "toggle_preview",
+            Command::PreviewUp => "preview_up",
+            Command::PreviewDown => "preview_down",
+            Command::OpenImage => "open_image",
+            Command::AddCase => "add_case",
+            Command::DeleteCase => "delete_case",
+            Command::ResetCases => "reset_cases",
+            Command::Palette => "palette",
+            Command::Help => "help",
+        }
+    }
+
+    /// Returns what the command does, as listed in the command palette.
+    pub fn description(self) -> &'static str {
+        match self {
+            Command::Quit => "Quit leetui",
+            Command::Back => "Go back",
+            Command::Up => "Move up",
+            Command::Down => "Move down",
+            Command::Left => "Move left",
+            Command::Right => "Move right",
+            Command::PageUp => "Move a page up",
+            Command::PageDown => "Move a page down",
+            Command::Select => "Select",
+            Command::Search => "Search problems",
+            Command::Daily => "Open the daily challenge",
+            Command::Sessions => "List suspended editors",
+            Command::Terminate => "Close the selected editor",
+            Command::TerminateAll => "Close every editor and quit",
+            Command::OpenEditor => "Open the solution in an external editor",
+            Command::CodeEditor => "Open the solution in the built-in editor",
+            Command::RunTests => "Run the test cases",
+            Command::Submit => "Submit the solution",
+            Command::TestCases => "Edit the test cases",
+            Command::Language => "Switch language",
+            Command::Files => "Pick a solution file",
+            Command::NewSolution => "Create a solution file",
+            Command::TogglePreview => "Toggle the solution preview",
+            Command::PreviewUp => "Scroll the preview up",
+            Command::PreviewDown => "Scroll the preview down",
+            Command::OpenImage => "Open the image of the description",
+            Command::AddCase => "Add a test case",
+            Command::DeleteCase => "Delete the test case",
+            Command::ResetCases => "Reset the test cases",
+            Command::Palette => "Show the command palette",
+            Command::Help => "Show every key",
+        }
+    }
+
+    /// Returns the group the command is listed under in the help overlay.
+    pub fn category(self) -> Category {
+        match self {
+            Command::Back
+            | Command::Up
+            | Command::Down
+            | Command::Left
+            | Command::Right
+            | Command::PageUp
+            | Command::PageDown
+            | Command::Select
+            | Command::PreviewUp
+            | Command::PreviewDown => Category::Navigation,
+            Command::Search
+            | Command::Daily
+            | Command::Language
+            | Command::Files
+            | Command::NewSolution
+            | Command::TogglePreview
+            | Command::OpenImage => Category::Problem,
+            Command::RunTests
+            | Command::Submit
+            | Command::TestCases
+            | Command::AddCase
+            | Command::DeleteCase
+            | Command::ResetCases => Category::Testing,
+            Command::OpenEditor
+            | Command::CodeEditor
+            | Command::Sessions
+            | Command::Terminate
+            | Command::TerminateAll => Category::Editors,
+            Command::Quit | Command::Palette | Command::Help => Category::General,
+        }
+    }
+
+    /// Whether the command is worth listing in the command palette, unlike
+    /// moving around, which is only useful as a key.
+    pub fn in_palette(self) -> bool {
+        !matches!(
+            self,
+            Command::Up
+                | Command::Down
+                | Command::Left
+                | Command::Right
+                | Command::PageUp
+                | Command::PageDown
+                | Command::Select
+                | Command::Back
+                | Command::PreviewUp
+                | Command::PreviewDown
+                | Command::Palette
+        )
+    }
+}
+
+/// The groups of commands in the help overlay.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Category {
+    Navigation,
+    Problem,
+    Testing,
+    Editors,
+    General,
+}
+
+impl Category {
+    /// Every category, in the order they're listed.
+    pub const ALL: [Category; 5] = [
+        Category::Navigation,
+        Category::Problem,
+        Category::Testing,
+        Category::Editors,
+        Category::General,
+    ];
+
+    pub fn title(self) -> &'static str {
+        match self {
+            Category::Navigation => "Navigation",
+            Category::Problem => "Problem",
+            Category::Testing => "Testing",
+            Category::Editors => "Editors",
+            Category::General => "General",
+        }
+    }
+}
+
+/// The screens with their own bindings.
+///
+/// The states where text is typed, like searching or the built-in editor,
+/// read keys directly and have none.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Scope {
+    Home,
+    Sessions,
+    Description,
+    TestCases,
+    Languages,
+    Solutions,
+}
+
+impl Scope {
+    pub const ALL: [Scope; 6] = [
+        Scope::Home,
+        Scope::Sessions,
+        Scope::Description,
+        Scope::TestCases,
+        Scope::Languages,
+        Scope::Solutions,
+    ];
+
+    /// Returns the name of the scope, as written in the configuration.
+    pub fn name(self) -> &'static str {
+        match self {
+            Scope::Home => "home",
+            Scope::Sessions => "sessions",
+            Scope::Description => "description",
+            Scope::TestCases => "test_cases",
+            Scope::Languages => "languages",
+            Scope::Solutions => "solutions",
+        }
+    }
+
+    /// Returns what the scope is called in the help overlay.
+    pub fn title(self) -> &'static str {
+        match self {
+            Scope::Home => "Problem list",
+            Scope::Sessions => "Editor sessions",
+            Scope::Description => "Description",
+            Scope::TestCases => "Test cases",
+            Scope::Languages => "Languages",
+            Scope::Solutions => "Solution files",
+        }
+    }
+
+    /// Returns the entries of the help bar, each being the commands shown
+    /// together and what they're described as.
+    pub fn help(self) -> &'static [(&'static [Command], &'static str)] {
+        use Command::*;
+
+        match self {
+            Scope::Home => &[
+                (&[Quit], "QUIT"),
+                (&[Help], "HELP"),
+                (&[Down, Up], "MOVE"),
+                (&[Daily], "DAILY"),
+                (&[Sessions], "SESSIONS"),
+                (&[Select], "SELECT"),
+                (&[Palette], "COMMANDS"),
+            ],
+            Scope::Sessions => &[
+                (&[Back], "BACK"),
+                (&[Help], "HELP"),
+                (&[Down, Up], "MOVE"),
+                (&[Select], "RESUME"),
+                (&[Terminate], "TERMINATE"),
+                (&[TerminateAll], "TERMINATE ALL AND QUIT"),
+            ],
+            Scope::Description => &[
+                (&[Back], "BACK"),
+                (&[Help], "HELP"),
+                (&[Down, Up], "MOVE"),
+                (&[OpenEditor], "EDITOR"),
+                (&[CodeEditor], "CODE"),
+                (&[RunTests], "TEST"),
+                (&[Submit], "SUBMIT"),
+                (&[TestCases], "CASES"),
+                (&[Language], "LANGUAGE"),
+                (&[Files], "FILES"),
+                (&[TogglePreview], "PREVIEW"),
+                (&[PreviewDown, PreviewUp], "SCROLL"),
+                (&[OpenImage], "IMAGE"),
+                (&[Palette], "COMMANDS"),
+            ],
+            Scope::TestCases => &[
+                (&[Back], "BACK"),
+                (&[Help], "HELP"),
+                (&[Left, Down, Up, Right], "MOVE"),
+                (&[AddCase], "ADD"),
+                (&[DeleteCase], "DELETE"),
+                (&[ResetCases], "RESET"),
+                (&[RunTests], "TEST"),
+                (&[Submit], "SUBMIT"),
+                (&[Language], "LANGUAGE"),
+                (&[Select], "SELECT"),
+            ],
+            Scope::Languages => &[
+                (&[Back], "BACK"),
+                (&[Help], "HELP"),
+                (&[Left, Down, Up, Right], "MOVE"),
+                (&[TestCases], "CASES"),
+                (&[Select], "SELECT"),
+            ],
+            Scope::Solutions => &[
+                (&[Back], "BACK"),
+                (&[Help], "HELP"),
+                (&[Down, Up], "MOVE"),
+                (&[NewSolution], "NEW"),
+                (&[Select], "SELECT"),
+            ],
+        }
+    }
+}
+
+/// The states where keys are typed as text, whose keys are fixed instead of
+/// bound in a scope. Their help overlay is opened with F1.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TextInput {
+    Search,
+    TestCaseField,
+    SolutionName,
+    CodeEditor,
+}
+
+impl TextInput {
+    /// Returns what the state is called in the help overlay.
+    pub fn title(self) -> &'static str {
+        match self {
+            TextInput::Search => "Search",
+            TextInput::TestCaseField => "Test case input",
+            TextInput::SolutionName => "New solution",
+            TextInput::CodeEditor => "Built-in editor",
+        }
+    }
+
+    /// Returns the keys of the state grouped under headings, each along with
+    /// what it does, read from the same tables the keys are dispatched with.
+    pub fn help(self) -> Vec<(&'static str, Vec<(String, &'static str)>)> {
+        let mut sections = match self {
+            TextInput::Search => vec![("Search", key_entries(SEARCH_KEYS))],
+            TextInput::TestCaseField => {
+                vec![("Test case input", key_entries(TEST_CASE_FIELD_KEYS))]
+            }
+            TextInput::SolutionName => vec![("New solution", key_entries(SOLUTION_NAME_KEYS))],
+            TextInput::CodeEditor => {
+                let commands = EX_COMMANDS
+                    .iter()
+                    .map(|binding| {
+                        let names: Vec<_> =
+                            binding.keys.iter().map(|name| format!(":{name}")).collect();
+                        (names.join(", "), binding.description)
+                    })
+                    .collect();
+
+                vec![
+                    ("Normal mode", key_entries(NORMAL_KEYS)),
+                    ("Insert mode", key_entries(INSERT_KEYS)),
+                    ("Commands", commands),
+                    ("Command and search prompts", key_entries(PROMPT_KEYS)),
+                ]
+            }
+        };
+
+        sections.push(("General", vec![(HELP_KEY.to_string(), "Show every key")]));
+        sections
+    }
+}
+
+/// A key of a state where text is typed, which can't be remapped unlike the
+/// commands of a scope.
+pub struct FixedBinding<K: 'static, A> {
+    /// Every way to trigger it.
+    pub keys: &'static [K],
+    pub action: A,
+    /// What it does, as listed in the help overlay.
+    pub description: &'static str,
+}
+
+/// A binding triggered by keys typed one after the other, like `gg`.
+pub type KeyBindings<A> = FixedBinding<&'static [KeyBinding], A>;
+
+/// What a key pressed in a state where text is typed resolves to.
+pub enum Lookup<A> {
+    Action(A),
+    /// The key starts a binding of several keys.
+    Pending,
+    Unbound,
+}
+
+/// Finds what a key does in a table of fixed bindings.
+///
+/// # Arguments
+/// * `table` - The bindings of the state.
+/// * `pending` - The key pressed before, if it started a binding of several keys.
+/// * `key` - The key pressed.
+pub fn lookup<A: Copy>(
+    table: &[KeyBindings<A>],
+    pending: Option<KeyBinding>,
+    key: KeyBinding,
+) -> Lookup<A> {
+    let find = |sequence: &[KeyBinding]| {
+        table
+            .iter()
+            .find(|binding| binding.keys.contains(&sequence))
+            .map(|binding| binding.action)
+    };
+
+    if let Some(pending) = pending
+        && let Some(action) = find(&[pending, key])
+    {
+        return Lookup::Action(action);
+    }
+
+    let starts_sequence = table
+        .iter()
+        .flat_map(|binding| binding.keys)
+        .any(|sequence| sequence.len() > 1 && sequence[0] == key);
+
+    match (starts_sequence, find(&[key])) {
+        (true, _) => Lookup::Pending,
+        (false, Some(action)) => Lookup::Action(action),
+        (false, None) => Lookup::Unbound,
+    }
+}
+
+/// Writes every way to trigger the bindings of a table, like `h, ←`.
+fn key_entries<A>(table: &[KeyBindings<A>]) -> Vec<(String, &'static str)> {
+    table
+        .iter()
+        .map(|binding| {
+            let keys: Vec<_> = binding
+                .keys
+                .iter()
+                .map(|sequence| {
+                    sequence
+                        .iter()
+                        .map(KeyBinding::to_string)
+                        .collect::<String>()
+                })
+                .collect();
+
+            (keys.join(", "), binding.description)
+        })
+        .collect()
+}
+
+const fn plain(code: KeyCode) -> KeyBinding {
+    KeyBinding::new(code, KeyModifiers::NONE)
+}
+
+const fn key(ch: char) -> KeyBinding {
+    plain(KeyCode::Char(ch))
+}
+
+const fn ctrl(ch: char) -> KeyBinding {
+    KeyBinding::new(KeyCode::Char(ch), KeyModifiers::CONTROL)
+}
+
+/// The key opening the help overlay of the states where text is typed.
+pub const HELP_KEY: KeyBinding = plain(KeyCode::F(1));
+
+/// What a key does in a text field.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FieldKey {
+    Confirm,
+    Cancel,
+    DeleteChar,
+}
+
+pub const SEARCH_KEYS: &[KeyBindings<FieldKey>] = &[
+    FixedBinding {
+        keys: &[&[plain(KeyCode::Enter)]],
+        action: FieldKey::Confirm,
+        description: "Search the problems",
+    },
+    FixedBinding {
+        keys: &[&[plain(KeyCode::Esc)]],
+        action: FieldKey::Cancel,
+        description: "Stop typing",
+    },
+    FixedBinding {
+        keys: &[&[plain(KeyCode::Backspace)]],
+        action: FieldKey::DeleteChar,
+        description: "Delete the last character",
+    },
+];
+
+pub const TEST_CASE_FIELD_KEYS: &[KeyBindings<FieldKey>] = &[
+    FixedBinding {
+        keys: &[&[plain(KeyCode::Enter)]],
+        action: FieldKey::Confirm,
+        description: "Confirm and go back to the description",
+    },
+    FixedBinding {
+        keys: &[&[plain(KeyCode::Esc)]],
+        action: FieldKey::Cancel,
+        description: "Confirm and go back to the test cases",
+    },
+    FixedBinding {
+        keys: &[&[plain(KeyCode::Backspace)]],
+        action: FieldKey::DeleteChar,
+        description: "Delete the last character",
+    },
+];
+
+pub const SOLUTION_NAME_KEYS: &[KeyBindings<FieldKey>] = &[
+    FixedBinding {
+        keys: &[&[plain(KeyCode::Enter)]],
+        action: FieldKey::Confirm,
+        description: "Create the solution",
+    },
+    FixedBinding {
+        keys: &[&[plain(KeyCode::Esc)]],
+        action: FieldKey::Cancel,
+        description: "Cancel",
+    },
+    FixedBinding {
+        keys: &[&[plain(KeyCode::Backspace)]],
+        action: FieldKey::DeleteChar,
+        description: "Delete the last character",
+    },
+];
+
+/// The keys of the built-in editor's `:` and `/` prompts.
+pub const PROMPT_KEYS: &[KeyBindings<FieldKey>] = &[
+    FixedBinding {
+        keys: &[&[plain(KeyCode::Enter)]],
+        action: FieldKey::Confirm,
+        description: "Run the command or search",
+    },
+    FixedBinding {
+        keys: &[&[plain(KeyCode::Esc)]],
+        action: FieldKey::Cancel,
+        description: "Back to normal mode",
+    },
+    FixedBinding {
+        keys: &[&[plain(KeyCode::Backspace)]],
+        action: FieldKey::DeleteChar,
+        description: "Delete the last character, or leave when empty",
+    },
+];
+
+/// What a key does in the built-in editor's normal mode.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NormalKey {
+    Left,
+    Down,
+    Up,
+    Right,
+    NextWord,
+    PreviousWord,
+    LineStart,
+    Indentation,
+    LineEnd,
+    FirstLine,
+    LastLine,
+    HalfPageDown,
+    HalfPageUp,
+    Insert,
+    Append,
+    InsertAtIndentation,
+    AppendAtEnd,
+    OpenBelow,
+    OpenAbove,
+    DeleteChar,
+    DeleteToEnd,
+    DeleteLine,
+    YankLine,
+    PasteBelow,
+    PasteAbove,
+    Undo,
+    Redo,
+    Search,
+    NextMatch,
+    PreviousMatch,
+    Command,
+    Save,
+    Blur,
+}
+
+pub const NORMAL_KEYS: &[KeyBindings<NormalKey>] = &[
+    FixedBinding {
+        keys: &[&[key('h')], &[plain(KeyCode::Left)]],
+        action: NormalKey::Left,
+        description: "Move left",
+    },
+    FixedBinding {
+        keys: &[&[key('j')], &[plain(KeyCode::Down)]],
+        action: NormalKey::Down,
+        description: "Move down",
+    },
+    FixedBinding {
+        keys: &[&[key('k')], &[plain(KeyCode::Up)]],
+        action: NormalKey::Up,
+        description: "Move up",
+    },
+    FixedBinding {
+        keys: &[&[key('l')], &[plain(KeyCode::Right)]],
+        action: NormalKey::Right,
+        description: "Move right",
+    },
+    FixedBinding {
+        keys: &[&[key('w')]],
+        action: NormalKey::NextWord,
+        description: "Next word",
+    },
+    FixedBinding {
+        keys: &[&[key('b')]],
+        action: NormalKey::PreviousWord,
+        description: "Previous word",
+    },
+    FixedBinding {
+        keys: &[&[key('0')], &[plain(KeyCode::Home)]],
+        action: NormalKey::LineStart,
+        description: "Start of the line",
+    },
+    FixedBinding {
+        keys: &[&[key('^')]],
+        action: NormalKey::Indentation,
+        description: "Indentation of the line",
+    },
+    FixedBinding {
+        keys: &[&[key('$')], &[plain(KeyCode::End)]],
+        action: NormalKey::LineEnd,
+        description: "End of the line",
+    },
+    FixedBinding {
+        keys: &[&[key('g'), key('g')]],
+        action: NormalKey::FirstLine,
+        description: "First line",
+    },
+    FixedBinding {
+        keys: &[&[key('G')]],
+        action: NormalKey::LastLine,
+        description: "Last line",
+    },
+    FixedBinding {
+        keys: &[&[ctrl('d')]],
+        action: NormalKey::HalfPageDown,
+        description: "Half a page down",
+    },
+    FixedBinding {
+        keys: &[&[ctrl('u')]],
+        action: NormalKey::HalfPageUp,
+        description: "Half a page up",
+    },
+    FixedBinding {
+        keys: &[&[key('i')]],
+        action: NormalKey::Insert,
+        description: "Insert before the cursor",
+    },
+    FixedBinding {
+        keys: &[&[key('a')]],
+        action: NormalKey::Append,
+        description: "Insert after the cursor",
+    },
+    FixedBinding {
+        keys: &[&[key('I')]],
+        action: NormalKey::InsertAtIndentation,
+        description: "Insert at the indentation",
+    },
+    FixedBinding {
+        keys: &[&[key('A')]],
+        action: NormalKey::AppendAtEnd,
+        description: "Insert at the end of the line",
+    },
+    FixedBinding {
+        keys: &[&[key('o')]],
+        action: NormalKey::OpenBelow,
+        description: "Open a line below",
+    },
+    FixedBinding {
+        keys: &[&[key('O')]],
+        action: NormalKey::OpenAbove,
+        description: "Open a line above",
+    },
+    FixedBinding {
+        keys: &[&[key('x')]],
+        action: NormalKey::DeleteChar,
+        description: "Delete a character",
+    },
+    FixedBinding {
+        keys: &[&[key('D')]],
+        action: NormalKey::DeleteToEnd,
+        description: "Delete to the end of the line",
+    },
+    FixedBinding {
+        keys: &[&[key('d'), key('d')]],
+        action: NormalKey::DeleteLine,
+        description: "Delete the line",
+    },
+    FixedBinding {
+        keys: &[&[key('y'), key('y')]],
+        action: NormalKey::YankLine,
+        description: "Yank the line",
+    },
+    FixedBinding {
+        keys: &[&[key('p')]],
+        action: NormalKey::PasteBelow,
+        description: "Paste below",
+    },
+    FixedBinding {
+        keys: &[&[key('P')]],
+        action: NormalKey::PasteAbove,
+        description: "Paste above",
+    },
+    FixedBinding {
+        keys: &[&[key('u')]],
+        action: NormalKey::Undo,
+        description: "Undo",
+    },
+    FixedBinding {
+        keys: &[&[ctrl('r')]],
+        action: NormalKey::Redo,
+        description: "Redo",
+    },
+    FixedBinding {
+        keys: &[&[key('/')]],
+        action: NormalKey::Search,
+        description: "Search",
+    },
+    FixedBinding {
+        keys: &[&[key('n')]],
+        action: NormalKey::NextMatch,
+        description: "Next match",
+    },
+    FixedBinding {
+        keys: &[&[key('N')]],
+        action: NormalKey::PreviousMatch,
+        description: "Previous match",
+    },
+    FixedBinding {
+        keys: &[&[key(':')]],
+        action: NormalKey::Command,
+        description: "Type a command",
+    },
+    FixedBinding {
+        keys: &[&[ctrl('s')]],
+        action: NormalKey::Save,
+        description: "Save",
+    },
+    FixedBinding {
+        keys: &[&[plain(KeyCode::Esc)]],
+        action: NormalKey::Blur,
+        description: "Focus the rest of the screen",
+    },
+];
+
+/// What a key does in the built-in editor's insert mode, besides typing.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InsertKey {
+    Normal,
+    Indent,
+    BreakLine,
+    Backspace,
+    Delete,
+    Left,
+    Down,
+    Up,
+    Right,
+    LineStart,
+    LineEnd,
+}
+
+pub const INSERT_KEYS: &[KeyBindings<InsertKey>] = &[
+    FixedBinding {
+        keys: &[&[plain(KeyCode::Esc)]],
+        action: InsertKey::Normal,
+        description: "Back to normal mode",
+    },
+    FixedBinding {
+        keys: &[&[plain(KeyCode::Tab)]],
+        action: InsertKey::Indent,
+        description: "Indent",
+    },
+    FixedBinding {
+        keys: &[&[plain(KeyCode::Enter)]],
+        action: InsertKey::BreakLine,
+        description: "Break the line, keeping the indentation",
+    },
+    FixedBinding {
+        keys: &[&[plain(KeyCode::Backspace)]],
+        action: InsertKey::Backspace,
+        description: "Delete the previous character",
+    },
+    FixedBinding {
+        keys: &[&[plain(KeyCode::Delete)]],
+        action: InsertKey::Delete,
+        description: "Delete the next character",
+    },
+    FixedBinding {
+        keys: &[&[plain(KeyCode::Left)]],
+        action: InsertKey::Left,
+        description: "Move left",
+    },
+    FixedBinding {
+        keys: &[&[plain(KeyCode::Down)]],
+        action: InsertKey::Down,
+        description: "Move down",
+    },
+    FixedBinding {
+        keys: &[&[plain(KeyCode::Up)]],
+        action: InsertKey::Up,
+        description: "Move up",
+    },
+    FixedBinding {
+        keys: &[&[plain(KeyCode::Right)]],
+        action: InsertKey::Right,
+        description: "Move right",
+    },
+    FixedBinding {
+        keys: &[&[plain(KeyCode::Home)]],
+        action: InsertKey::LineStart,
+        description: "Start of the line",
+    },
+    FixedBinding {
+        keys: &[&[plain(KeyCode::End)]],
+        action: InsertKey::LineEnd,
+        description: "End of the line",
+    },
+];
+
+/// A command typed after `:` in the built-in editor.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExCommand {
+    Write,
+    WriteQuit,
+    Quit,
+    ForceQuit,
+    GoToLine,
+}
+
+/// The name a line number is matched as among the commands.
+pub const LINE_COMMAND: &str = "<line>";
+
+pub const EX_COMMANDS: &[FixedBinding<&str, ExCommand>] = &[
+    FixedBinding {
+        keys: &["w"],
+        action: ExCommand::Write,
+        description: "Save",
+    },
+    FixedBinding {
+        keys: &["wq", "x"],
+        action: ExCommand::WriteQuit,
+        description: "Save and close",
+    },
+    FixedBinding {
+        keys: &["q"],
+        action: ExCommand::Quit,
+        description: "Close, unless there are unsaved changes",
+    },
+    FixedBinding {
+        keys: &["q!"],
+        action: ExCommand::ForceQuit,
+        description: "Close and discard the changes",
+    },
+    FixedBinding {
+        keys: &[LINE_COMMAND],
+        action: ExCommand::GoToLine,
+        description: "Go to a line",
+    },
+];
+
+/// A key along with the modifiers held down, like `ctrl-d`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct KeyBinding {
+    code: KeyCode,
+    modifiers: KeyModifiers,
+}
+
+impl KeyBinding {
+    const fn new(code: KeyCode, modifiers: KeyModifiers) -> Self {
+        Self { code, modifiers }
+    }
+
+    /// Returns the binding a key event matches.
+    ///
+    /// Shift is dropped from characters since it's already part of them, `J` is `J`
+    /// whether the terminal reports shift or not.
+    pub fn from_event(key: KeyEvent) -> Self {
+        let mut modifiers =
+            key.modifiers & (KeyModifiers::CONTROL | KeyModifiers::ALT | KeyModifiers::SHIFT);
+        if let KeyCode::Char(_) = key.code {
+            modifiers.remove(KeyModifiers::SHIFT);
+        }
+
+        Self::new(key.code, modifiers)
+    }
+
+    /// Whether the binding is a single character without modifiers, which the
+    /// help bar writes next to each other, like `jk`.
+    fn is_plain_char(&self) -> bool {
+        matches!(self.code, KeyCode::Char(ch) if ch != ' ') && self.modifiers.is_empty()
+    }
+}
+
+impl FromStr for KeyBinding {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut modifiers = KeyModifiers::NONE;
+        let mut rest = s;
+
+        // The last character is always part of the key, so `ctrl--` binds ctrl and minus.
+        while let Some((prefix, key)) = rest.split_once('-')
+            && !key.is_empty()
+        {
+            modifiers |= match prefix.to_lowercase().as_str() {
+                "ctrl" | "c" => KeyModifiers::CONTROL,
+                "alt" | "m" => KeyModifiers::ALT,
+                "shift" | "s" => KeyModifiers::SHIFT,
+                _ => return Err(format!("unknown modifier {prefix:?} in {s:?}")),
+            };
+            rest = key;
+        }
+
+        let mut chars = rest.chars();
+        let code = match (chars.next(), chars.next()) {
+            (Some(ch), None) => KeyCode::Char(ch),
+            _ => match rest.to_lowercase().as_str() {
+                "enter" | "return" => KeyCode::Enter,
+                "esc" | "escape" => KeyCode::Esc,
+                "tab" => KeyCode::Tab,
+                "backtab" => KeyCode::BackTab,
+                "backspace" => KeyCode::Backspace,
+                "delete" | "del" => KeyCode::Delete,
+                "space" => KeyCode::Char(' '),
+                "up" => KeyCode::Up,
+                "down" => KeyCode::Down,
+                "left" => KeyCode::Left,
+                "right" => KeyCode::Right,
+                "pageup" => KeyCode::PageUp,
+                "pagedown" => KeyCode::PageDown,
+                "home" => KeyCode::Home,
+                "end" => KeyCode::End,
+                name => match name.strip_prefix('f').and_then(|n| n.parse().ok()) {
+                    Some(n @ 1..=12) => KeyCode::F(n),
+                    _ => return Err(format!("unknown key {s:?}")),
+                },
+            },
+        };
+
+        // Shifted characters are matched as the character itself.
+        let code = match code {
+            KeyCode::Char(ch) if modifiers.contains(KeyModifiers::SHIFT) => {
+                modifiers.remove(KeyModifiers::SHIFT);
+                KeyCode::Char(ch.to_ascii_uppercase())
+            }
+            code => code,
+        };
+
+        Ok(Self::new(code, modifiers))
+    }
+}
+
+impl Display for KeyBinding {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.modifiers.contains(KeyModifiers::CONTROL) {
+            f.write_str("ctrl-")?;
+        }
+        if self.modifiers.contains(KeyModifiers::ALT) {
+            f.write_str("alt-")?;
+        }
+        if self.modifiers.contains(KeyModifiers::SHIFT) {
+            f.write_str("shift-")?;
+        }
+
+        match self.code {
+            KeyCode::Char(' ') => f.write_str("space"),
+            KeyCode::Char(ch) => write!(f, "{ch}"),
+            KeyCode::Enter => f.write_str("enter"),
+            KeyCode::Esc => f.write_str("esc"),
+            KeyCode::Tab => f.write_str("tab"),
+            KeyCode::BackTab => f.write_str("backtab"),
+            KeyCode::Backspace => f.write_str("backspace"),
+            KeyCode::Delete => f.write_str("del"),
+            KeyCode::Up => f.write_str("↑"),
+            KeyCode::Down => f.write_str("↓"),
+            KeyCode::Left => f.write_str("←"),
+            KeyCode::Right => f.write_str("→"),
+            KeyCode::PageUp => f.write_str("pgup"),
+            KeyCode::PageDown => f.write_str("pgdn"),
+            KeyCode::Home => f.write_str("home"),
+            KeyCode::End => f.write_str("end"),
+            KeyCode::F(n) => write!(f, "f{n}"),
+            code => write!(f, "{code:?}"),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for KeyBinding {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        value.parse().map_err(de::Error::custom)
+    }
+}
+
+/// The sets of movement keys to start from.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Preset {
+    /// `hjkl`, with ctrl-d and ctrl-u for pages.
+    #[default]
+    Vim,
+    /// The arrow keys, with page up and page down.
+    Arrows,
+    /// Ctrl with `p`, `n`, `b` and `f` as well as the arrow keys, with ctrl-v and alt-v for pages.
+    Emacs,
+}
+
+/// One or several keys bound to a command.
+#[derive(Debug, Clone)]
+pub enum Keys {
+    One(KeyBinding),
+    Many(Vec<KeyBinding>),
+}
+
+impl<'de> Deserialize<'de> for Keys {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        // The key is parsed here so its error isn't replaced by a generic one.
+        match toml::Value::deserialize(deserializer)? {
+            toml::Value::String(key) => key.parse().map(Keys::One).map_err(de::Error::custom),
+            value @ toml::Value::Array(_) => Vec::deserialize(value)
+                .map(Keys::Many)
+                .map_err(de::Error::custom),
+            _ => Err(de::Error::custom("keys must be a key or a list of keys")),
+        }
+    }
+}
+
+impl Keys {
+    fn into_vec(self) -> Vec<KeyBinding> {
+        match self {
+            Keys::One(key) => vec![key],
+            Keys::Many(keys) => keys,
+        }
+    }
+}
+
+/// The keys section of the configuration: a preset, and the commands of
+/// each scope bound to other keys.
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct KeysConfig {
+    pub preset: Preset,
+    home: HashMap<Command, Keys>,
+    sessions: HashMap<Command, Keys>,
+    description: HashMap<Command, Keys>,
+    test_cases: HashMap<Command, Keys>,
+    languages: HashMap<Command, Keys>,
+    solutions: HashMap<Command, Keys>,
+}
+
+impl KeysConfig {
+    /// Returns the bindings the user changed in a scope.
+    fn overrides(&self, scope: Scope) -> &HashMap<Command, Keys> {
+        match scope {
+            Scope::Home => &self.home,
+            Scope::Sessions => &self.sessions,
+            Scope::Description => &self.description,
+            Scope::TestCases => &self.test_cases,
+            Scope::Languages => &self.languages,
+            Scope::Solutions => &self.solutions,
+        }
+    }
+}
+
+/// The keys bound to every command of every scope.
+#[derive(Debug, Clone)]
+pub struct Keymap {
+    scopes: HashMap<Scope, Vec<(Command, Vec<KeyBinding>)>>,
+}
+
+impl Default for Keymap {
+    fn default() -> Self {
+        Self::preset(Preset::Vim)
+    }
+}
+
+impl Keymap {
+    /// Builds the bindings of a preset.
+    pub fn preset(preset: Preset) -> Self {
+        use Command::*;
+
+        let key = |code| KeyBinding::new(code, KeyModifiers::NONE);
+        let ch = |ch| key(KeyCode::Char(ch));
+        let ctrl = |ch| KeyBinding::new(KeyCode::Char(ch), KeyModifiers::CONTROL);
+        let alt = |ch| KeyBinding::new(KeyCode::Char(ch), KeyModifiers::ALT);
+        let esc = key(KeyCode::Esc);
+        let enter = key(KeyCode::Enter);
+
+        let arrows = [
+            key(KeyCode::Up),
+            key(KeyCode::Down),
+            key(KeyCode::Left),
+            key(KeyCode::Right),
+        ];
+
+        // Emacs moves up with ctrl-p, so it opens the palette like `M-x` instead.
+        let palette = match preset {
+            Preset::Vim | Preset::Arrows => vec![ch(':'), ctrl('p')],
+            Preset::Emacs => vec![ch(':'), alt('x')],
+        };
+
+        let [up, down, left, right, page_up, page_down] = match preset {
+            Preset::Vim => [
+                vec![ch('k')],
+                vec![ch('j')],
+                vec![ch('h')],
+                vec![ch('l')],
+                vec![ctrl('u')],
+                vec![ctrl('d')],
+            ],
+            Preset::Arrows => [
+                vec![arrows[0]],
+                vec![arrows[1]],
+                vec![arrows[2]],
+                vec![arrows[3]],
+                vec![key(KeyCode::PageUp)],
+                vec![key(KeyCode::PageDown)],
+            ],
+            Preset::Emacs => [
+                vec![ctrl('p'), arrows[0]],
+                vec![ctrl('n'), arrows[1]],
+                vec![ctrl('b'), arrows[2]],
+                vec![ctrl('f'), arrows[3]],
+                vec![alt('v'), key(KeyCode::PageUp)],
+                vec![ctrl('v'), key(KeyCode::PageDown)],
+            ],
+        };
+
+        let scopes = HashMap::from([
+            (
+                Scope::Home,
+                vec![
+                    (Quit, vec![esc]),
+                    (Down, down.clone()),
+                    (Up, up.clone()),
+                    (PageDown, page_down.clone()),
+                    (PageUp, page_up.clone()),
+                    (Search, vec![ch('/')]),
+                    (Daily, vec![ch('d')]),
+                    (Sessions, vec![ch('S')]),
+                    (Select, vec![enter]),
+                    (Palette, palette.clone()),
+                    (Help, vec![ch('?')]),
+                ],
+            ),
+            (
+                Scope::Sessions,
+                vec![
+                    (Back, vec![esc]),
+                    (Down, down.clone()),
+                    (Up, up.clone()),
+                    (Select, vec![enter]),
+                    (Terminate, vec![ch('x')]),
+                    (TerminateAll, vec![ch('q')]),
+                    (Palette, palette.clone()),
+                    (Help, vec![ch('?')]),
+                ],
+            ),
+            (
+                Scope::Description,
+                vec![
+                    (Back, vec![esc]),
+                    (Down, down.clone()),
+                    (Up, up.clone()),
+                    (OpenEditor, vec![ch('e')]),
+                    (CodeEditor, vec![ch('i')]),
+                    (RunTests, vec![ch('r')]),
+                    (Submit, vec![ch('s')]),
+                    (TestCases, vec![ch('t')]),
+                    (Language, vec![ch('c')]),
+                    (Files, vec![ch('f')]),
+                    (TogglePreview, vec![ch('p')]),
+                    (PreviewDown, vec![ch('J')]),
+                    (PreviewUp, vec![ch('K')]),
+                    (OpenImage, vec![ch('o')]),
+                    (Palette, palette.clone()),
+                    (Help, vec![ch('?')]),
+                ],
+            ),
+            (
+                Scope::TestCases,
+                vec![
+                    (Back, vec![esc, ch('t')]),
+                    (Left, left.clone()),
+                    (Down, down.clone()),
+                    (Up, up.clone()),
+                    (Right, right.clone()),
+                    (AddCase, vec![ch('a')]),
+                    (DeleteCase, vec![ch('d')]),
+                    (ResetCases, vec![ch('R')]),
+                    (RunTests, vec![ch('r')]),
+                    (Submit, vec![ch('s')]),
+                    (Language, vec![ch('c')]),
+                    (Select, vec![enter]),
+                    (Palette, palette.clone()),
+                    (Help, vec![ch('?')]),
+                ],
+            ),
+            (
+                Scope::Languages,
+                vec![
+                    (Back, vec![esc, ch('c')]),
+                    (Left, left),
+                    (Down, down.clone()),
+                    (Up, up.clone()),
+                    (Right, right),
+                    (TestCases, vec![ch('t')]),
+                    (Select, vec![enter]),
+                    (Palette, palette.clone()),
+                    (Help, vec![ch('?')]),
+                ],
+            ),
+            (
+                Scope::Solutions,
+                vec![
+                    (Back, vec![esc, ch('f')]),
+                    (Down, down),
+                    (Up, up),
+                    (NewSolution, vec![ch('n')]),
+                    (Select, vec![enter]),
+                    (Palette, palette.clone()),
+                    (Help, vec![ch('?')]),
+                ],
+            ),
+        ]);
+
+        Self { scopes }
+    }
+
+    /// Builds the keymap of the configuration, checking that no key is bound
+    /// to two commands of the same scope.
+    ///
+    /// # Arguments
+    /// * `config` - The keys section of the configuration.
+    ///
+    /// # Returns
+    /// The keymap, or an error describing the first invalid binding.
+    pub fn from_config(config: &KeysConfig) -> Result<Self, String> {
+        let mut keymap = Self::preset(config.preset);
+
+        for scope in Scope::ALL {
+            let bindings = keymap.scopes.get_mut(&scope).unwrap();
+
+            for (command, keys) in config.overrides(scope) {
+                let Some((_, bound)) = bindings.iter_mut().find(|(cmd, _)| cmd == command) else {
+                    return Err(format!(
+                        "{} can't be bound in [keys.{}]",
+                        command.name(),
+                        scope.name()
+                    ));
+                };
+
+                *bound = keys.clone().into_vec();
+            }
+
+            let mut seen: HashMap<KeyBinding, Command> = HashMap::new();
+            for (command, keys) in bindings.iter() {
+                for key in keys {
+                    if let Some(other) = seen.insert(*key, *command)
+                        && other != *command
+                    {
+                        return Err(format!(
+                            "{key} is bound to both {} and {} in [keys.{}]",
+                            other.name(),
+                            command.name(),
+                            scope.name()
+                        ));
+                    }
+                }
+            }
+        }
+
+        Ok(keymap)
+    }
+
+    /// Returns the command a key is bound to in a scope.
+    pub fn command(&self, scope: Scope, key: KeyEvent) -> Option<Command> {
+        let key = KeyBinding::from_event(key);
+
+        self.scopes[&scope]
+            .iter()
+            .find(|(_, keys)| keys.contains(&key))
+            .map(|(command, _)| *command)
+    }
+
+    /// Returns every command of a scope along with its keys, in the order they were defined.
+    pub fn bindings(&self, scope: Scope) -> &[(Command, Vec<KeyBinding>)] {
+        &self.scopes[&scope]
+    }
+
+    /// Returns the keys of a command as written in the help bar, like `jk`,
+    /// `ctrl-n/ctrl-p`, or `None` if they aren't bound.
+    ///
+    /// # Arguments
+    /// * `scope` - The scope the commands are bound in.
+    /// * `commands` - The commands shown together, only their first keys are used.
+    pub fn describe(&self, scope: Scope, commands: &[Command]) -> Option<String> {
+        let keys: Vec<_> = commands
+            .iter()
+            .filter_map(|command| {
+                self.bindings(scope)
+                    .iter()
+                    .find(|(cmd, _)| cmd == command)
+                    .and_then(|(_, keys)| keys.first())
+            })
+            .collect();
+
+        if keys.is_empty() {
+            return None;
+        }
+
+        let separator = match keys.iter().all(|key| key.is_plain_char()) {
+            true => "",
+            false => "/",
+        };
+
+        Some(
+            keys.iter()
+                .map(|key| key.to_string())
+                .collect::<Vec<_>>()
+                .join(separator),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Looks up a key of the built-in editor's normal mode.
+    fn normal(pending: Option<&str>, key: &str) -> Option<NormalKey> {
+        let pending = pending.map(|key| key.parse().unwrap());
+        match lookup(NORMAL_KEYS, pending, key.parse().unwrap()) {
+            Lookup::Action(action) => Some(action),
+            _ => None,
+        }
+    }
+
+    #[test]
+    fn completes_sequences() {
+        assert!(matches!(
+            lookup(NORMAL_KEYS, None, key('g')),
+            Lookup::Pending
+        ));
+        assert_eq!(normal(Some("g"), "g"), Some(NormalKey::FirstLine));
+        assert_eq!(normal(Some("d"), "d"), Some(NormalKey::DeleteLine));
+        assert_eq!(normal(Some("d"), "j"), Some(NormalKey::Down));
+    }
+
+    #[test]
+    fn tells_modifiers_apart() {
+        assert_eq!(normal(None, "ctrl-d"), Some(NormalKey::HalfPageDown));
+        assert_eq!(normal(None, "G"), Some(NormalKey::LastLine));
+        assert_eq!(normal(None, "ctrl-h"), None);
+    }
+
+    #[test]
+    fn lists_every_binding_in_help() {
+        let help = TextInput::CodeEditor.help();
+        let entries = |heading| help.iter().find(|(h, _)| *h == heading).unwrap().1.len();
+
+        assert_eq!(entries("Normal mode"), NORMAL_KEYS.len());
+        assert_eq!(entries("Insert mode"), INSERT_KEYS.len());
+        assert_eq!(entries("Commands"), EX_COMMANDS.len());
+        assert!(
+            help.iter()
+                .any(|(_, keys)| keys.iter().any(|(k, _)| k == "gg"))
+        );
+        assert!(
+            help.iter()
+                .any(|(_, keys)| keys.iter().any(|(k, _)| k == ":wq, :x"))
+        );
+    }
+}
@@ -1,8 +1,17 @@
+#[allow(clippy::module_inception)]
 mod app;
+mod code_editor;
+pub mod config;
+mod external_editor;
 pub mod handler;
+pub mod images;
+pub mod keymap;
+mod palette;
 mod rendering;
+pub mod theme;
 pub mod utils;
+mod watcher;
+mod workspace;
 
-pub(super) use app::HomeInputState;
 pub use app::{Action, App, UpdateResult};
-pub use rendering::*;
+pub(super) use app::{HomeInputState, MouseTarget};
@@ -0,0 +1,165 @@
+use api::Difficulty;
+
+use super::keymap::Command;
+
+/// What choosing an entry of the palette does.
+#[derive(Debug, Clone)]
+pub enum PaletteAction {
+    Command(Command),
+    /// Opens a problem by its id, like `1`, or its slug, like `two-sum`.
+    OpenProblem(String),
+    OpenInBrowser,
+    /// Lists only the problems of a difficulty, or all of them.
+    Filter(Option<Difficulty>),
+    Theme(&'static str),
+}
+
+/// An entry of the palette along with the keys that do the same, if any.
+#[derive(Debug, Clone)]
+pub struct PaletteEntry {
+    pub label: String,
+    pub keys: Option<String>,
+    pub action: PaletteAction,
+}
+
+/// The command palette, listing what can be done in the current state
+/// filtered by what was typed.
+pub struct Palette {
+    pub input: String,
+    pub selected: usize,
+    entries: Vec<PaletteEntry>,
+    visible: Vec<PaletteEntry>,
+}
+
+impl Palette {
+    /// Opens the palette with the entries available right now.
+    pub fn new(entries: Vec<PaletteEntry>) -> Self {
+        Self {
+            input: String::new(),
+            selected: 0,
+            visible: entries.clone(),
+            entries,
+        }
+    }
+
+    /// Returns the entries that match the input, best first.
+    pub fn visible(&self) -> &[PaletteEntry] {
+        &self.visible
+    }
+
+    /// Returns the entry that enter would choose.
+    pub fn selected_entry(&self) -> Option<&PaletteEntry> {
+        self.visible.get(self.selected)
+    }
+
+    pub fn push(&mut self, ch: char) {
+        self.input.push(ch);
+        self.refilter();
+    }
+
+    pub fn pop(&mut self) {
+        self.input.pop();
+        self.refilter();
+    }
+
+    pub fn select_next(&mut self) {
+        if self.selected + 1 < self.visible.len() {
+            self.selected += 1;
+        }
+    }
+
+    pub fn select_previous(&mut self) {
+        self.selected = self.selected.saturating_sub(1);
+    }
+
+    /// Sorts the entries matching the input by how well they match, putting
+    /// an entry to open the typed problem first if it looks like an id or slug.
+    fn refilter(&mut self) {
+        let query = self.input.trim();
+        let mut scored: Vec<_> = self
+            .entries
+            .iter()
+            .filter_map(|entry| fuzzy_score(query, &entry.label).map(|score| (score, entry)))
+            .collect();
+
+        // The sort is stable, so entries that score the same keep their order.
+        scored.sort_by_key(|(score, _)| -score);
+
+        let mut matches: Vec<_> = scored.into_iter().map(|(_, entry)| entry.clone()).collect();
+        let open_problem = open_problem_entry(query);
+
+        // A single word is more likely the start of a command than a slug,
+        // so it only comes first when nothing else matches.
+        let is_word = query.chars().all(|ch| ch.is_ascii_lowercase());
+        self.visible = match is_word && !matches.is_empty() {
+            true => {
+                matches.extend(open_problem);
+                matches
+            }
+            false => open_problem.into_iter().chain(matches).collect(),
+        };
+
+        self.selected = 0;
+    }
+}
+
+/// Builds the entry that opens the problem typed into the palette.
+fn open_problem_entry(query: &str) -> Option<PaletteEntry> {
+    let is_id = !query.is_empty() && query.chars().all(|ch| ch.is_ascii_digit());
+    // Slugs may be a single word, like `candy`, a fetch fails if there's no such problem.
+    let is_slug = query.starts_with(|ch: char| ch.is_ascii_lowercase())
+        && query
+            .chars()
+            .all(|ch| ch.is_ascii_lowercase() || ch.is_ascii_digit() || ch == '-');
+
+    let label = match (is_id, is_slug) {
+        (true, _) => format!("Open problem #{query}"),
+        (_, true) => format!("Open problem {query}"),
+        _ => return None,
+    };
+
+    Some(PaletteEntry {
+        label,
+        keys: None,
+        action: PaletteAction::OpenProblem(query.to_string()),
+    })
+}
+
+/// Scores how well some text matches a query whose characters it contains in
+/// order, favoring consecutive characters and the starts of words.
+///
+/// # Returns
+/// The score, higher being better, or `None` if the text doesn't match.
+fn fuzzy_score(query: &str, text: &str) -> Option<i64> {
+    let mut score = 0;
+    let mut text_chars = text.chars().enumerate();
+    let mut preceding = ' ';
+    let mut last_match: Option<usize> = None;
+
+    for query_ch in query.chars().filter(|ch| !ch.is_whitespace()) {
+        let query_ch = query_ch.to_ascii_lowercase();
+
+        loop {
+            let (i, ch) = text_chars.next()?;
+            let at_word_start = preceding == ' ';
+            preceding = ch;
+
+            if ch.to_ascii_lowercase() != query_ch {
+                continue;
+            }
+
+            score += 1;
+            if last_match.is_some_and(|j| j + 1 == i) {
+                score += 4;
+            }
+            if at_word_start {
+                score += 3;
+            }
+
+            last_match = Some(i);
+            break;
+        }
+    }
+
+    Some(score)
+}
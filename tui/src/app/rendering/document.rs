@@ -0,0 +1,285 @@
+use api::{Block, Document, Example, Image, Inline, inline_text, subscript, superscript};
+use ratatui::{
+    layout::Size,
+    style::{Modifier, Style},
+    text::{Line, Text},
+};
+
+use super::text::TextBuilder;
+use crate::app::{images::Images, theme::Theme};
+
+/// A description rendered into text, along with where its images go.
+pub struct RenderedDocument {
+    pub text: Text<'static>,
+    pub images: Vec<ImagePlacement>,
+}
+
+/// The place of an image inside a rendered description.
+pub struct ImagePlacement {
+    pub url: String,
+    pub line: usize,
+    pub column: usize,
+    /// The size of the rows left blank for the image, or `None` if it's
+    /// shown as a placeholder instead.
+    pub size: Option<Size>,
+}
+
+/// Renders a problem description into styled text wrapped to the given width.
+///
+/// # Arguments
+/// * `doc` - The parsed description.
+/// * `width` - The amount of columns the text has to fit in.
+/// * `images` - The images that can be drawn, to leave room for them.
+/// * `theme` - The colors to draw the description with.
+///
+/// # Returns
+/// The text, already wrapped, so it has one line per row on screen.
+pub fn document_to_text(
+    doc: &Document,
+    width: u16,
+    images: &Images,
+    theme: &Theme,
+) -> RenderedDocument {
+    let mut writer = Writer {
+        builder: TextBuilder::new(width),
+        images,
+        theme,
+        placements: Vec::new(),
+    };
+
+    writer.blocks(&doc.blocks);
+
+    RenderedDocument {
+        text: writer.builder.finish(),
+        images: writer.placements,
+    }
+}
+
+struct Writer<'a> {
+    builder: TextBuilder,
+    images: &'a Images,
+    theme: &'a Theme,
+    placements: Vec<ImagePlacement>,
+}
+
+impl Writer<'_> {
+    fn blocks(&mut self, blocks: &[Block]) {
+        for block in blocks {
+            self.block(block);
+        }
+    }
+
+    fn block(&mut self, block: &Block) {
+        match block {
+            Block::Paragraph(inlines) => {
+                self.inlines(inlines);
+                self.builder.end_block();
+            }
+            Block::Heading(inlines) => {
+                self.styled(Style::new().fg(self.theme.accent).bold(), inlines);
+                self.builder.end_block();
+            }
+            Block::Example(example) => self.example(example),
+            Block::Constraints(items) => {
+                self.label("Constraints:");
+                self.builder.end_block();
+
+                for item in items {
+                    self.builder
+                        .push_indent("• ", "  ", Style::new().fg(self.theme.muted));
+                    self.inlines(item);
+                    self.builder.newline_if_started();
+                    self.builder.pop_indent();
+                }
+
+                self.builder.end_block();
+            }
+            Block::Code(code) => {
+                self.builder.end_block();
+                self.builder
+                    .push_indent(" ", " ", Style::new().bg(self.theme.surface));
+                self.builder
+                    .push_style(Style::new().bg(self.theme.surface).fg(self.theme.text));
+                self.builder
+                    .set_fill(Some(Style::new().bg(self.theme.surface)));
+                // Descriptions don't say what their code is written in, if
+                // it's code at all, so it's shown as is.
+                let lines: Vec<_> = code
+                    .lines()
+                    .map(|line| Line::raw(line.to_string()))
+                    .collect();
+                self.builder.highlighted(&lines);
+                self.builder.end_block();
+                self.builder.set_fill(None);
+                self.builder.pop_style();
+                self.builder.pop_indent();
+            }
+            Block::Image(image) => self.figure(image),
+            Block::List { ordered, items } => {
+                for (i, item) in items.iter().enumerate() {
+                    let bullet = match ordered {
+                        true => format!("{}. ", i + 1),
+                        false => "• ".to_string(),
+                    };
+
+                    let rest = " ".repeat(bullet.chars().count());
+                    self.builder
+                        .push_indent(&bullet, &rest, Style::new().fg(self.theme.muted));
+
+                    // Descriptions don't tell tight lists apart, so items are kept compact.
+                    for block in item {
+                        match block {
+                            Block::Paragraph(inlines) => {
+                                self.inlines(inlines);
+                                self.builder.newline_if_started();
+                            }
+                            _ => self.block(block),
+                        }
+                    }
+
+                    self.builder.newline_if_started();
+                    self.builder.pop_indent();
+                }
+
+                self.builder.end_block();
+            }
+            Block::Quote(blocks) => {
+                self.builder
+                    .push_indent("│ ", "│ ", Style::new().fg(self.theme.muted));
+                self.builder
+                    .push_style(Style::new().fg(self.theme.subtle).italic());
+                self.blocks(blocks);
+                self.builder.pop_style();
+                self.builder.pop_indent();
+            }
+            Block::Rule => self.builder.rule(Style::new().fg(self.theme.muted)),
+        }
+    }
+
+    fn example(&mut self, example: &Example) {
+        self.styled(
+            Style::new().fg(self.theme.accent).bold(),
+            &[Inline::Text(format!("{}:", example.title))],
+        );
+        self.builder.newline();
+
+        self.builder
+            .push_indent("│ ", "│ ", Style::new().fg(self.theme.muted));
+
+        for image in &example.images {
+            self.figure(image);
+        }
+
+        self.field("Input: ", &example.input);
+        self.field("Output: ", &example.output);
+
+        match example.explanation.split_first() {
+            Some((Block::Paragraph(inlines), rest)) => {
+                self.label("Explanation: ");
+                self.inlines(inlines);
+                self.builder.newline();
+                self.blocks(rest);
+            }
+            Some(_) => {
+                self.label("Explanation:");
+                self.builder.newline();
+                self.blocks(&example.explanation);
+            }
+            None => {}
+        }
+
+        self.builder.end_block();
+        self.builder.pop_indent();
+    }
+
+    /// Writes a labeled line of an example, keeping the value's line breaks.
+    fn field(&mut self, label: &str, value: &str) {
+        self.label(label);
+
+        for (i, line) in value.lines().enumerate() {
+            if i > 0 {
+                self.builder.newline();
+            }
+
+            self.builder.text(line);
+        }
+
+        self.builder.newline();
+    }
+
+    fn label(&mut self, label: &str) {
+        self.builder.push_style(Style::new().bold());
+        self.builder.text(label);
+        self.builder.pop_style();
+    }
+
+    /// Writes an image on its own, leaving room to draw it if the terminal can.
+    fn figure(&mut self, image: &Image) {
+        let max_width = self.builder.available_width() as u16;
+
+        let (line, column, size) = match self.images.size(&image.src, max_width) {
+            Some(size) => {
+                let (line, column) = self.builder.reserve(size.height);
+                (line, column, Some(size))
+            }
+            None => {
+                self.builder.end_block();
+                let line = self.builder.next_line();
+                self.placeholder(image);
+                self.builder.end_block();
+                (line, 0, None)
+            }
+        };
+
+        self.placements.push(ImagePlacement {
+            url: image.src.clone(),
+            line,
+            column,
+            size,
+        });
+    }
+
+    fn placeholder(&mut self, image: &Image) {
+        let label = match image.alt.is_empty() {
+            true => "[image]".to_string(),
+            false => format!("[image: {}]", image.alt),
+        };
+
+        self.builder
+            .push_style(Style::new().fg(self.theme.muted).italic());
+        self.builder.text(&label);
+        self.builder.pop_style();
+    }
+
+    fn inlines(&mut self, inlines: &[Inline]) {
+        for inline in inlines {
+            match inline {
+                Inline::Text(text) => self.builder.text(text),
+                Inline::Strong(content) => self.styled(Style::new().bold(), content),
+                Inline::Emphasis(content) => self.styled(Style::new().italic(), content),
+                Inline::Code(content) => self.styled(
+                    Style::new().bg(self.theme.surface).fg(self.theme.warning),
+                    content,
+                ),
+                Inline::Superscript(content) => {
+                    self.builder.text(&superscript(&inline_text(content)))
+                }
+                Inline::Subscript(content) => self.builder.text(&subscript(&inline_text(content))),
+                Inline::Link { content, .. } => self.styled(
+                    Style::new()
+                        .fg(self.theme.warning)
+                        .add_modifier(Modifier::UNDERLINED),
+                    content,
+                ),
+                Inline::Image(image) => self.placeholder(image),
+                Inline::LineBreak => self.builder.newline(),
+            }
+        }
+    }
+
+    fn styled(&mut self, style: Style, content: &[Inline]) {
+        self.builder.push_style(style);
+        self.inlines(content);
+        self.builder.pop_style();
+    }
+}
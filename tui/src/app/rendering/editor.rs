@@ -3,11 +3,18 @@ use ratatui::{
     layout::{Alignment, Constraint, Direction, HorizontalAlignment, Layout, Rect},
     style::{Color, Style, Styled, Stylize},
     text::{Line, Span},
-    widgets::{Block, Borders, Cell, Padding, Paragraph, Row, Table, Tabs, Wrap},
+    widgets::{Block, Borders, Cell, Padding, Paragraph, Row, Table, Tabs},
+};
+use ratatui_image::Image;
+
+use super::{document, utils};
+use crate::app::{
+    App, MouseTarget,
+    app::EditorState,
+    code_editor::{Mode, TAB_WIDTH, display_width},
+    theme::Theme,
+    workspace,
 };
-
-use super::utils;
-use crate::app::{App, app::EditorState};
 
 pub fn description(f: &mut Frame, rect: Rect, app: &mut App) {
     let question = app.question.as_ref().unwrap();
@@ -16,30 +23,223 @@ pub fn description(f: &mut Frame, rect: Rect, app: &mut App) {
         .title(format!(" {}. {} ", question.question_id, question.title))
         .title_alignment(HorizontalAlignment::Center);
 
-    let md = utils::markdown_to_text(&question.content);
-    let inner_width = rect.width.saturating_sub(2);
-    let inner_height = rect.height.saturating_sub(2);
+    let block = block.padding(Padding::horizontal(1));
+    let inner = block.inner(rect);
+    let rendered =
+        document::document_to_text(&app.description, inner.width, &app.images, &app.theme);
 
-    let mut total_visual_rows = 0;
-    for line in &md.lines {
-        let w = line.width() as u16;
-        if w == 0 {
-            total_visual_rows += 1;
-        } else {
-            total_visual_rows +=
-                (w + inner_width - 1) / (if inner_width == 0 { 1 } else { inner_width });
+    let max_scroll = rendered
+        .text
+        .lines
+        .len()
+        .saturating_sub(inner.height as usize);
+    app.description_offset = app.description_offset.min(max_scroll);
+
+    let offset = app.description_offset;
+    let visible = offset..offset + inner.height as usize;
+
+    let paragraph = Paragraph::new(rendered.text)
+        .block(block)
+        .scroll((offset as u16, 0));
+
+    f.render_widget(paragraph, rect);
+    app.mouse_targets.push((rect, MouseTarget::Description));
+
+    app.visible_images = rendered
+        .images
+        .iter()
+        .filter(|placement| visible.contains(&placement.line))
+        .map(|placement| placement.url.clone())
+        .collect();
+
+    for placement in rendered.images {
+        let Some(size) = placement.size else {
+            continue;
+        };
+
+        // Images are only drawn while they fit whole, since not every protocol can crop them.
+        let end = placement.line + size.height as usize;
+        if placement.line < visible.start || end > visible.end {
+            continue;
+        }
+
+        let area = Rect::new(
+            inner.x + placement.column as u16,
+            inner.y + (placement.line - offset) as u16,
+            size.width,
+            size.height,
+        );
+
+        if let Some(protocol) = app.images.protocol(&placement.url, size) {
+            f.render_widget(Image::new(protocol), area);
         }
     }
+}
 
-    let max_scroll = total_visual_rows.saturating_sub(inner_height);
-    app.description_offset = app.description_offset.min(max_scroll as usize);
+pub fn solution_preview(f: &mut Frame, rect: Rect, app: &mut App) {
+    let title = match app.preview {
+        Some(ref preview) => format!(" {} ", preview.source),
+        None => " preview ".to_string(),
+    };
+
+    let block = Block::bordered()
+        .title(title)
+        .title_alignment(HorizontalAlignment::Center)
+        .padding(Padding::horizontal(1));
 
-    let paragraph = Paragraph::new(md)
+    let Some(ref preview) = app.preview else {
+        let inner = block.inner(rect);
+        f.render_widget(block, rect);
+        utils::render_empty_background(f, inner, " NO LANGUAGE SELECTED ", &app.theme);
+        return;
+    };
+
+    let inner = block.inner(rect);
+    let max_scroll = preview.lines.len().saturating_sub(inner.height as usize);
+    app.preview_offset = app.preview_offset.min(max_scroll);
+
+    let paragraph = Paragraph::new(preview.lines.clone())
         .block(block)
-        .wrap(Wrap { trim: true })
-        .scroll((app.description_offset as u16, 0));
+        .scroll((app.preview_offset as u16, 0));
 
     f.render_widget(paragraph, rect);
+    app.mouse_targets.push((rect, MouseTarget::Preview));
+}
+
+pub fn code_editor(f: &mut Frame, rect: Rect, app: &mut App) {
+    let theme = &app.theme;
+    let focused = matches!(app.editor_state, EditorState::Coding);
+    let Some(ref mut editor) = app.code_editor else {
+        return;
+    };
+
+    let modified = if editor.is_modified() { " [+]" } else { "" };
+    let border_color = match focused {
+        true => theme.warning,
+        false => theme.muted,
+    };
+
+    let block = Block::bordered()
+        .title(format!(" {}{modified} ", editor.file_name()))
+        .title_alignment(HorizontalAlignment::Center)
+        .border_style(Style::default().fg(border_color));
+
+    let inner = block.inner(rect);
+    f.render_widget(block, rect);
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Min(0), Constraint::Length(1)])
+        .split(inner);
+
+    let area = chunks[0];
+    let gutter = editor.lines.len().to_string().len().max(3) + 2;
+    let width = (area.width as usize).saturating_sub(gutter);
+    editor.scroll_to_cursor(area.height as usize, width);
+
+    let number_style = Style::default().fg(theme.muted);
+    let (cursor_row, scroll_row, scroll_col) = (editor.row, editor.scroll_row, editor.scroll_col);
+    let line_count = editor.lines.len();
+    let highlighted = editor.highlighted(theme);
+
+    let lines: Vec<_> = (scroll_row..line_count)
+        .take(area.height as usize)
+        .map(|row| {
+            let style = match row == cursor_row {
+                true => number_style.fg(theme.subtle),
+                false => number_style,
+            };
+
+            let number = format!("{:>1$} ", row + 1, gutter - 1);
+            let mut spans = vec![Span::styled(number, style)];
+
+            if let Some(line) = highlighted.get(row) {
+                spans.extend(clip_spans(&line.spans, scroll_col, width));
+            }
+
+            Line::from(spans)
+        })
+        .collect();
+
+    f.render_widget(Paragraph::new(lines), area);
+
+    let status = match editor.mode {
+        Mode::Command => Line::from(format!(":{}", editor.prompt)),
+        Mode::Search => Line::from(format!("/{}", editor.prompt)),
+        mode => {
+            let label = match mode {
+                Mode::Insert => "-- INSERT --",
+                _ => "",
+            };
+
+            let text = editor.message.as_deref().unwrap_or(label);
+            Line::from(Span::styled(
+                text.to_string(),
+                Style::default().fg(theme.subtle),
+            ))
+        }
+    };
+
+    let position = format!("{}:{} ", editor.row + 1, editor.col + 1);
+    let status_chunks = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([
+            Constraint::Min(0),
+            Constraint::Length(position.len() as u16),
+        ])
+        .split(chunks[1]);
+
+    f.render_widget(Paragraph::new(status), status_chunks[0]);
+    f.render_widget(Paragraph::new(position.fg(theme.muted)), status_chunks[1]);
+
+    if focused {
+        let (x, y) = match editor.mode {
+            Mode::Command | Mode::Search => (editor.prompt.chars().count() + 1, chunks[1].y),
+            _ => {
+                let column = editor.cursor_column() - editor.scroll_col;
+                (
+                    gutter + column,
+                    area.y + (editor.row - editor.scroll_row) as u16,
+                )
+            }
+        };
+
+        f.set_cursor_position((inner.x + x as u16, y));
+    }
+}
+
+/// Cuts highlighted spans to the columns shown on screen, expanding tabs.
+///
+/// # Arguments
+/// * `spans` - The spans of a whole line.
+/// * `skip` - The amount of columns scrolled past.
+/// * `width` - The amount of columns shown.
+fn clip_spans(spans: &[Span<'static>], skip: usize, width: usize) -> Vec<Span<'static>> {
+    let mut clipped = Vec::new();
+    let mut column = 0;
+
+    for span in spans {
+        let mut content = String::new();
+
+        for ch in span.content.chars() {
+            let (text, ch_width) = match ch {
+                '\t' => (" ".repeat(TAB_WIDTH), TAB_WIDTH),
+                ch => (ch.to_string(), display_width(std::iter::once(ch))),
+            };
+
+            if column >= skip && column + ch_width <= skip + width {
+                content.push_str(&text);
+            }
+
+            column += ch_width;
+        }
+
+        if !content.is_empty() {
+            clipped.push(Span::styled(content, span.style));
+        }
+    }
+
+    clipped
 }
 
 pub fn test_cases_languages_pane(f: &mut Frame, rect: Rect, app: &mut App) {
@@ -58,20 +258,29 @@ pub fn test_cases_languages_pane(f: &mut Frame, rect: Rect, app: &mut App) {
 
     match app.editor_state {
         EditorState::SelectingLanguage => language_grid(f, main_chunks[1], app),
-        EditorState::TestCases => {
+        EditorState::SelectingSolution | EditorState::NamingSolution => {
+            solution_list(f, main_chunks[1], app)
+        }
+        // The fields are also shown unfocused when the layout leaves room for them.
+        _ if main_chunks[1].height > 0 => {
             app.last_test_case_viewport_height = main_chunks[1].height;
             test_case_fields(f, main_chunks[1], app);
         }
-        EditorState::EditingTestCaseField => test_case_fields(f, main_chunks[1], app),
         _ => {}
     }
 }
 
 fn language_selector(f: &mut Frame, rect: Rect, app: &mut App) {
-    let color = if matches!(app.editor_state, EditorState::SelectingLanguage) {
-        Color::Rgb(255, 160, 80)
+    let theme = &app.theme;
+    let color = if matches!(
+        app.editor_state,
+        EditorState::SelectingLanguage
+            | EditorState::SelectingSolution
+            | EditorState::NamingSolution
+    ) {
+        theme.warning
     } else {
-        Color::DarkGray
+        theme.muted
     };
 
     let border_style = Style::new().fg(color);
@@ -83,15 +292,63 @@ fn language_selector(f: &mut Frame, rect: Rect, app: &mut App) {
     f.render_widget(block, rect);
 
     let text_style = Style::new().fg(color).bold();
-    let text = match app.selected_language {
-        Some(ref lang) => format!(" {lang}").set_style(text_style),
-        None => " none".set_style(text_style),
+    let file_name = app
+        .selected_solution
+        .as_ref()
+        .and_then(|path| path.file_name())
+        .map(|name| name.to_string_lossy());
+
+    let text = match (&app.selected_language, file_name) {
+        (Some(lang), Some(name)) => format!(" {lang} · {name}").set_style(text_style),
+        (Some(lang), None) => format!(" {lang}").set_style(text_style),
+        (None, _) => " none".set_style(text_style),
     };
 
     f.render_widget(text, inner);
 }
 
+fn solution_list(f: &mut Frame, rect: Rect, app: &mut App) {
+    let theme = &app.theme;
+    let current = app.selected_solution.as_ref();
+    let dir_path = workspace::problem_dir(&app.question.as_ref().unwrap().title_slug);
+    let mut lines: Vec<_> = app
+        .solution_paths
+        .iter()
+        .enumerate()
+        .map(|(i, path)| {
+            let name = path
+                .strip_prefix(&dir_path)
+                .unwrap_or(path)
+                .to_string_lossy();
+            let marker = if Some(path) == current { " *" } else { "" };
+
+            let style = match i == app.solution_selection_index {
+                true => Style::default().fg(theme.accent).bold(),
+                false => Style::default().fg(theme.muted),
+            };
+
+            Line::from(format!("  {name}{marker}").set_style(style))
+        })
+        .collect();
+
+    if lines.is_empty() {
+        lines.push(Line::from("  no solutions yet".fg(theme.muted)));
+    }
+
+    if let EditorState::NamingSolution = app.editor_state {
+        let input = format!("  new: {}", app.new_solution_name);
+        let width = input.len() as u16;
+        lines.push(Line::from(input.fg(theme.warning).bold()));
+
+        let y = rect.y + lines.len() as u16 - 1;
+        f.set_cursor_position((rect.x + width, y));
+    }
+
+    f.render_widget(Paragraph::new(lines), rect);
+}
+
 fn language_grid(f: &mut Frame, rect: Rect, app: &mut App) {
+    let theme = &app.theme;
     let question = app.question.as_ref().unwrap();
 
     let languages: Vec<_> = question
@@ -113,9 +370,9 @@ fn language_grid(f: &mut Frame, rect: Rect, app: &mut App) {
                 let is_selected = index == app.language_selection_index;
 
                 let style = if is_selected {
-                    Style::default().fg(Color::Rgb(0, 255, 150)).bold()
+                    Style::default().fg(theme.accent).bold()
                 } else {
-                    Style::default().fg(Color::DarkGray)
+                    Style::default().fg(theme.muted)
                 };
 
                 let text = format!("  {lang}");
@@ -130,51 +387,101 @@ fn language_grid(f: &mut Frame, rect: Rect, app: &mut App) {
     }
 
     let widths = vec![Constraint::Percentage(100 / columns as u16); columns];
+    let column_areas = Layout::horizontal(widths.clone()).spacing(2).split(rect);
     let table = Table::new(table_rows, widths).column_spacing(2);
 
     f.render_widget(table, rect);
+
+    for index in 0..languages.len() {
+        let (r, c) = (index % rows, index / rows);
+        let column = column_areas[c];
+        let area = Rect::new(column.x, rect.y + r as u16, column.width, 1).intersection(rect);
+        app.mouse_targets.push((area, MouseTarget::Language(index)));
+    }
 }
 
-fn test_case_tabs(frame: &mut Frame, area: Rect, app: &App) {
+fn test_case_tabs(frame: &mut Frame, area: Rect, app: &mut App) {
+    let theme = &app.theme;
     let selected_color = match app.editor_state {
-        EditorState::Description | EditorState::SelectingLanguage => Color::DarkGray,
-        _ => Color::Rgb(255, 160, 80),
+        EditorState::Description
+        | EditorState::SelectingLanguage
+        | EditorState::Coding
+        | EditorState::SelectingSolution
+        | EditorState::NamingSolution => theme.muted,
+        _ => theme.warning,
     };
 
-    let unselected_style = Style::default().fg(Color::DarkGray);
+    let unselected_style = Style::default().fg(theme.muted);
     let selected_style = Style::default().bg(Color::Reset).fg(selected_color).bold();
 
-    let titles = app.test_cases.iter().enumerate().map(|(i, _)| {
+    let titles = app.test_cases.iter().enumerate().map(|(i, case)| {
         let style = if i == app.selected_test_case {
             selected_style
         } else {
             unselected_style
         };
 
-        Line::from(format!(" Case {} ", i + 1)).style(style)
+        let mark = match case.passed() {
+            Some(true) => " ✓".fg(theme.accent),
+            Some(false) => " ✗".fg(theme.error),
+            None => "".into(),
+        };
+
+        Line::from(vec![
+            Span::raw(format!(" Case {}", i + 1)),
+            mark,
+            Span::raw(" "),
+        ])
+        .style(style)
     });
 
     let border_color = match app.editor_state {
-        EditorState::Description | EditorState::SelectingLanguage => Color::DarkGray,
-        _ => Color::Rgb(255, 160, 80),
+        EditorState::Description
+        | EditorState::SelectingLanguage
+        | EditorState::Coding
+        | EditorState::SelectingSolution
+        | EditorState::NamingSolution => theme.muted,
+        _ => theme.warning,
     };
 
     let border_style = Style::default().fg(border_color);
-    let outer_block = Block::bordered()
+    let mut outer_block = Block::bordered()
         .border_style(border_style)
         .title_alignment(HorizontalAlignment::Right)
         .title(" TEST CASES ");
 
-    let tabs = Tabs::new(titles).divider("").select(app.selected_test_case);
+    if let Some(ref status) = app.run_status {
+        let color = if status.accepted {
+            theme.accent
+        } else {
+            theme.error
+        };
+
+        let title = format!(" {} ", status.title.to_uppercase());
+        outer_block = outer_block.title(Line::from(title.fg(color).bold()).left_aligned());
+    }
+
+    let titles: Vec<_> = titles.collect();
     let inner = outer_block.inner(area);
 
+    // Tabs pad each title with a space on both sides.
+    let mut x = inner.x;
+    for (i, title) in titles.iter().enumerate() {
+        let width = title.width() as u16 + 2;
+        let tab = Rect::new(x, inner.y, width, 1).intersection(inner);
+        app.mouse_targets.push((tab, MouseTarget::TestCase(i)));
+        x = x.saturating_add(width);
+    }
+
+    let tabs = Tabs::new(titles).divider("").select(app.selected_test_case);
     frame.render_widget(tabs, inner);
     frame.render_widget(outer_block, area);
 }
 
-fn test_case_fields(frame: &mut Frame, area: Rect, app: &App) {
+fn test_case_fields(frame: &mut Frame, area: Rect, app: &mut App) {
+    let theme = &app.theme;
     if app.test_cases.is_empty() {
-        utils::render_empty_background(frame, area, " NO TEST CASES DEFINED ");
+        utils::render_empty_background(frame, area, " NO TEST CASES DEFINED ", theme);
         return;
     }
 
@@ -188,15 +495,17 @@ fn test_case_fields(frame: &mut Frame, area: Rect, app: &App) {
     let question = app.question.as_ref().unwrap();
     let case = &app.test_cases[app.selected_test_case];
     let param_names = &question.meta_data.params;
+    let results = app.result_fields();
+    let field_count = param_names.len() + results.len();
 
     let mut constraints = Vec::new();
-    for _ in 0..param_names.len() {
+    for _ in 0..field_count {
         constraints.push(Constraint::Length(1));
         constraints.push(Constraint::Length(3));
         constraints.push(Constraint::Length(1));
     }
 
-    let total_height = (param_names.len() * 5 + 10) as u16;
+    let total_height = (field_count * 5 + 10) as u16;
     let virtual_area = Rect {
         x: inner_area.x,
         y: inner_area
@@ -211,7 +520,8 @@ fn test_case_fields(frame: &mut Frame, area: Rect, app: &App) {
         .constraints(constraints)
         .split(virtual_area);
 
-    for i in 0..param_names.len() {
+    let mut fields = Vec::new();
+    for (i, param) in param_names.iter().enumerate() {
         let chunk_base = i * 3;
         let label_chunk = chunks[chunk_base];
         let box_chunk = chunks[chunk_base + 1];
@@ -219,16 +529,51 @@ fn test_case_fields(frame: &mut Frame, area: Rect, app: &App) {
         let clipped_label = label_chunk.intersection(inner_area);
         let clipped_box = box_chunk.intersection(inner_area);
 
-        if inner_area.height > 0 || inner_area.height > 0 {
+        let label_color = if i == app.selected_case_text {
+            theme.accent
+        } else {
+            theme.muted
+        };
+
+        let error = param.validate(&case.input[i]).err();
+
+        if inner_area.height > 0 {
             render_parameter_block(
                 frame,
                 clipped_label,
                 clipped_box,
-                &param_names[i].name,
+                Span::styled(param.name.as_str(), label_color),
                 &case.input[i],
-                i == app.selected_case_text,
+                error.as_deref(),
+                theme,
             );
         }
+
+        let field = clipped_label.union(clipped_box).intersection(inner_area);
+        fields.push((field, MouseTarget::CaseField(i)));
+    }
+
+    for (i, field) in results.iter().enumerate() {
+        let chunk_base = (param_names.len() + i) * 3;
+        let clipped_label = chunks[chunk_base].intersection(inner_area);
+        let clipped_box = chunks[chunk_base + 1].intersection(inner_area);
+
+        let label_color = match field.passed {
+            Some(true) => theme.accent,
+            Some(false) => theme.error,
+            None => theme.muted,
+        };
+
+        let value = field.value.lines().next().unwrap_or_default();
+        render_parameter_block(
+            frame,
+            clipped_label,
+            clipped_box,
+            Span::styled(field.label, label_color),
+            value,
+            None,
+            theme,
+        );
     }
 
     if let EditorState::EditingTestCaseField = app.editor_state {
@@ -238,27 +583,34 @@ fn test_case_fields(frame: &mut Frame, area: Rect, app: &App) {
             frame.set_cursor_position((box_chunk.x + 2 + val_len, box_chunk.y + 1));
         }
     }
+
+    app.mouse_targets.extend(fields);
 }
 
 fn render_parameter_block(
     frame: &mut Frame,
     label_area: Rect,
     box_area: Rect,
-    label: &str,
+    label: Span,
     value: &str,
-    is_selected: bool,
+    error: Option<&str>,
+    theme: &Theme,
 ) {
-    let fg = if is_selected {
-        Color::Rgb(0, 255, 150)
-    } else {
-        Color::DarkGray
-    };
+    let label_style = label.style.bold();
+    let mut label_line = Line::from(format!("{} =", label.content).set_style(label_style));
+    if let Some(error) = error {
+        label_line.push_span(format!("  ✗ {error}").fg(theme.error));
+    }
+
+    frame.render_widget(Paragraph::new(label_line), label_area);
 
-    let paragraph = Paragraph::new(format!("{label} =").fg(fg).bold());
-    frame.render_widget(paragraph, label_area);
+    let color = theme.surface;
+    let border_color = match error {
+        Some(_) => theme.error,
+        None => color,
+    };
 
-    let color = Color::Rgb(50, 50, 50);
-    let border_style = Style::default().fg(color);
+    let border_style = Style::default().fg(border_color);
     let block = Block::default()
         .bg(color)
         .borders(Borders::ALL)
@@ -269,83 +621,51 @@ fn render_parameter_block(
 }
 
 pub fn editor_controls(frame: &mut Frame, rect: Rect, app: &mut App) {
-    let chunks = Layout::default()
-        .direction(Direction::Horizontal)
-        .constraints([Constraint::Length(20), Constraint::Min(0)])
-        .split(rect);
+    let theme = &app.theme;
+    let rect = utils::render_status(frame, rect, app);
 
-    if let Some(..) = app.error_message {
-        let err_text = format!("! ERROR");
-        let style = Style::default().fg(Color::Rgb(255, 45, 85));
-        let span = Span::styled(err_text, style);
-        let paragraph = Paragraph::new(span);
-        frame.render_widget(paragraph, chunks[0]);
-    } else if app.is_loading {
-        let spinner = ["⠋", "⠙", "⠹", "⠸", "⠼", "⠴", "⠦", "⠧", "⠇", "⠏"];
-        let spinner_frame = spinner[app.spinner_index % spinner.len()];
-        let loading_text = format!(" {spinner_frame} FETCHING");
-        let style = Style::default().fg(Color::Rgb(0, 255, 150));
-        let span = Span::styled(loading_text, style);
-        let paragraph = Paragraph::new(span);
-        frame.render_widget(paragraph, chunks[0]);
-    }
+    let keys_style = Style::default().fg(theme.subtle);
+    let desc_style = Style::default().fg(theme.muted);
 
-    let keys_style = Style::default().fg(Color::Gray);
-    let desc_style = Style::default().fg(Color::DarkGray);
+    if let Some(scope) = app.key_scope() {
+        utils::render_key_help(frame, rect, app, scope);
+        return;
+    }
 
     let current_keys = match app.editor_state {
-        EditorState::SelectingLanguage => Line::from(vec![
+        EditorState::EditingTestCaseField => Line::from(vec![
             Span::styled("esc ", keys_style),
             Span::styled("BACK  ", desc_style),
-            Span::styled("jk ", keys_style),
-            Span::styled("MOVE  ", desc_style),
-            Span::styled("t ", keys_style),
-            Span::styled("CASES  ", desc_style),
+            Span::styled("f1 ", keys_style),
+            Span::styled("HELP  ", desc_style),
             Span::styled("enter ", keys_style),
-            Span::styled("SELECT  ", desc_style),
-        ]),
-        EditorState::Description => Line::from(vec![
-            Span::styled("esc ", keys_style),
-            Span::styled("BACK  ", desc_style),
-            Span::styled("jk ", keys_style),
-            Span::styled("MOVE  ", desc_style),
-            Span::styled("e ", keys_style),
-            Span::styled("EDITOR  ", desc_style),
-            Span::styled("r ", keys_style),
-            Span::styled("TEST  ", desc_style),
-            Span::styled("s ", keys_style),
-            Span::styled("SUBMIT  ", desc_style),
-            Span::styled("t ", keys_style),
-            Span::styled("CASES  ", desc_style),
-            Span::styled("c ", keys_style),
-            Span::styled("LANGUAGE  ", desc_style),
+            Span::styled("CONFIRM  ", desc_style),
         ]),
-        EditorState::TestCases => Line::from(vec![
+        EditorState::NamingSolution => Line::from(vec![
             Span::styled("esc ", keys_style),
-            Span::styled("BACK  ", desc_style),
-            Span::styled("hjkl ", keys_style),
-            Span::styled("MOVE  ", desc_style),
-            Span::styled("a ", keys_style),
-            Span::styled("ADD  ", desc_style),
-            Span::styled("d ", keys_style),
-            Span::styled("DELETE  ", desc_style),
-            Span::styled("r ", keys_style),
-            Span::styled("TEST  ", desc_style),
-            Span::styled("s ", keys_style),
-            Span::styled("SUBMIT  ", desc_style),
-            Span::styled("c ", keys_style),
-            Span::styled("LANGUAGE  ", desc_style),
+            Span::styled("CANCEL  ", desc_style),
+            Span::styled("f1 ", keys_style),
+            Span::styled("HELP  ", desc_style),
             Span::styled("enter ", keys_style),
-            Span::styled("SELECT  ", desc_style),
+            Span::styled("CREATE  ", desc_style),
         ]),
-        EditorState::EditingTestCaseField => Line::from(vec![
+        EditorState::Coding => Line::from(vec![
             Span::styled("esc ", keys_style),
             Span::styled("BACK  ", desc_style),
-            Span::styled("hjkl ", keys_style),
-            Span::styled("MOVE  ", desc_style),
-            Span::styled("enter ", keys_style),
-            Span::styled("SELECT  ", desc_style),
+            Span::styled("f1 ", keys_style),
+            Span::styled("HELP  ", desc_style),
+            Span::styled("i ", keys_style),
+            Span::styled("INSERT  ", desc_style),
+            Span::styled("u ", keys_style),
+            Span::styled("UNDO  ", desc_style),
+            Span::styled("/ ", keys_style),
+            Span::styled("SEARCH  ", desc_style),
+            Span::styled(":w ", keys_style),
+            Span::styled("SAVE  ", desc_style),
+            Span::styled(":q ", keys_style),
+            Span::styled("CLOSE  ", desc_style),
         ]),
+        _ => Line::default(),
     };
 
     let help = Paragraph::new(current_keys).alignment(Alignment::Center);
@@ -0,0 +1,121 @@
+use ratatui::{
+    Frame,
+    layout::{Alignment, Rect},
+    style::Style,
+    text::{Line, Span},
+    widgets::{Block, Clear, Padding, Paragraph},
+};
+
+use crate::app::{
+    App,
+    keymap::{Category, Scope},
+};
+
+/// Renders every key of the current state over the whole screen, grouped
+/// under headings, if the help overlay is open.
+///
+/// # Arguments
+/// * `f` - The frame to render the widgets.
+/// * `rect` - The area to cover.
+/// * `app` - The main application.
+pub fn help_overlay(f: &mut Frame, rect: Rect, app: &mut App) {
+    if !app.show_help {
+        return;
+    }
+
+    let (title, sections) = match (app.key_scope(), app.text_input()) {
+        (Some(scope), _) => (scope.title(), scope_sections(app, scope)),
+        (None, Some(input)) => (input.title(), input.help()),
+        (None, None) => return,
+    };
+
+    let theme = &app.theme;
+    let keys_width = sections
+        .iter()
+        .flat_map(|(_, entries)| entries)
+        .map(|(keys, _)| keys.chars().count())
+        .max()
+        .unwrap_or_default()
+        + 3;
+
+    let mut lines = Vec::new();
+    for (heading, entries) in sections {
+        if !lines.is_empty() {
+            lines.push(Line::default());
+        }
+
+        lines.push(Line::styled(
+            heading,
+            Style::default().fg(theme.accent).bold(),
+        ));
+
+        for (keys, description) in entries {
+            lines.push(Line::from(vec![
+                Span::styled(
+                    format!("  {keys:<keys_width$}"),
+                    Style::default().fg(theme.subtle),
+                ),
+                Span::styled(description, Style::default().fg(theme.text)),
+            ]));
+        }
+    }
+
+    let keys_style = Style::default().fg(theme.subtle);
+    let desc_style = Style::default().fg(theme.muted);
+    let hints = Line::from(vec![
+        Span::styled(" esc ", keys_style),
+        Span::styled("CLOSE  ", desc_style),
+        Span::styled("↑↓ ", keys_style),
+        Span::styled("SCROLL ", desc_style),
+    ]);
+
+    let block = Block::bordered()
+        .title(format!(" Keys · {title} "))
+        .title_alignment(Alignment::Center)
+        .title_bottom(hints.centered())
+        .border_style(Style::default().fg(theme.accent))
+        .padding(Padding::new(2, 2, 1, 1));
+
+    f.render_widget(Clear, rect);
+
+    let inner = block.inner(rect);
+    let max_offset = lines.len().saturating_sub(inner.height as usize);
+    app.help_offset = app.help_offset.min(max_offset);
+
+    let paragraph = Paragraph::new(lines)
+        .block(block)
+        .scroll((app.help_offset as u16, 0));
+
+    f.render_widget(paragraph, rect);
+}
+
+/// The headings of the help overlay, each with the keys listed under it and
+/// what they do.
+type Sections = Vec<(&'static str, Vec<(String, &'static str)>)>;
+
+/// Lists every key bound in a scope, grouped by the category of its command.
+fn scope_sections(app: &App, scope: Scope) -> Sections {
+    let bindings: Vec<_> = app
+        .keymap
+        .bindings(scope)
+        .iter()
+        .filter(|(command, keys)| !keys.is_empty() && app.command_available(*command))
+        .collect();
+
+    Category::ALL
+        .into_iter()
+        .map(|category| {
+            let entries = bindings
+                .iter()
+                .filter(|(command, _)| command.category() == category)
+                .map(|(command, keys)| {
+                    let keys: Vec<_> = keys.iter().map(|key| key.to_string()).collect();
+                    (keys.join(", "), command.description())
+                })
+                .collect();
+
+            (category.title(), entries)
+        })
+        .filter(|(_, entries): &(_, Vec<_>)| !entries.is_empty())
+        .collect()
+}
@@ -0,0 +1,102 @@
+use std::sync::LazyLock;
+
+use api::Language;
+use ratatui::{
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+};
+use syntect::{
+    easy::HighlightLines,
+    highlighting::{self, FontStyle, ThemeSet},
+    parsing::{SyntaxReference, SyntaxSet},
+    util::LinesWithEndings,
+};
+
+use crate::app::theme::Theme;
+
+/// The grammars bundled with syntect, loaded the first time something is highlighted.
+static SYNTAXES: LazyLock<SyntaxSet> = LazyLock::new(SyntaxSet::load_defaults_newlines);
+
+/// The color schemes bundled with syntect.
+static SYNTAX_THEMES: LazyLock<ThemeSet> = LazyLock::new(ThemeSet::load_defaults);
+
+/// Whether syntect bundles a color scheme, like `InspiredGitHub` or `base16-ocean.dark`.
+pub fn syntax_theme_exists(name: &str) -> bool {
+    SYNTAX_THEMES.themes.contains_key(name)
+}
+
+/// Finds the grammar of a language, borrowing a close one for the
+/// languages syntect doesn't bundle.
+fn syntax_for(lang: Language) -> Option<&'static SyntaxReference> {
+    let ext = match lang {
+        Language::TypeScript => "js",
+        Language::Kotlin | Language::Dart => "java",
+        Language::Racket => "lisp",
+        lang => lang.ext(),
+    };
+
+    SYNTAXES.find_syntax_by_extension(ext)
+}
+
+/// Converts a syntect style into a ratatui one, keeping only the foreground
+/// so the highlighted code sits on whatever background it's drawn over.
+fn convert_style(style: highlighting::Style) -> Style {
+    let fg = style.foreground;
+    let mut converted = Style::new().fg(Color::Rgb(fg.r, fg.g, fg.b));
+
+    if style.font_style.contains(FontStyle::BOLD) {
+        converted = converted.add_modifier(Modifier::BOLD);
+    }
+    if style.font_style.contains(FontStyle::ITALIC) {
+        converted = converted.add_modifier(Modifier::ITALIC);
+    }
+    if style.font_style.contains(FontStyle::UNDERLINE) {
+        converted = converted.add_modifier(Modifier::UNDERLINED);
+    }
+
+    converted
+}
+
+/// Highlights source code, one line of text per line of code.
+///
+/// # Arguments
+/// * `code` - The source code.
+/// * `lang` - The language the code is written in, if known.
+/// * `theme` - The theme whose color scheme is used.
+///
+/// # Returns
+/// The styled lines, unstyled if the language has no grammar or the theme no color scheme.
+pub fn highlight(code: &str, lang: Option<Language>, theme: &Theme) -> Vec<Line<'static>> {
+    let scheme = theme
+        .syntax
+        .as_ref()
+        .and_then(|name| SYNTAX_THEMES.themes.get(name));
+
+    let (Some(syntax), Some(scheme)) = (lang.and_then(syntax_for), scheme) else {
+        return code
+            .lines()
+            .map(|line| Line::raw(line.to_string()))
+            .collect();
+    };
+
+    let mut highlighter = HighlightLines::new(syntax, scheme);
+    let mut lines = Vec::new();
+
+    for line in LinesWithEndings::from(code) {
+        let spans = match highlighter.highlight_line(line, &SYNTAXES) {
+            Ok(regions) => regions
+                .into_iter()
+                .map(|(style, text)| {
+                    let text = text.trim_end_matches(['\n', '\r']);
+                    Span::styled(text.to_string(), convert_style(style))
+                })
+                .filter(|span| !span.content.is_empty())
+                .collect(),
+            Err(_) => vec![Span::raw(line.trim_end_matches(['\n', '\r']).to_string())],
+        };
+
+        lines.push(Line::from(spans));
+    }
+
+    lines
+}
@@ -4,10 +4,13 @@ use ratatui::{
     layout::{Alignment, Constraint, Direction, Layout, Rect},
     style::{Color, Style, Styled, Stylize},
     text::{Line, Span},
-    widgets::{Block, Borders, Cell, HighlightSpacing, Paragraph, Row, Table},
+    widgets::{
+        Block, Borders, Cell, Clear, HighlightSpacing, Padding, Paragraph, Row, Table, TableState,
+    },
 };
 
-use crate::app::{App, HomeInputState};
+use super::utils;
+use crate::app::{App, HomeInputState, MouseTarget};
 
 /// Renders the user's profile into the given frame.
 ///
@@ -16,6 +19,7 @@ use crate::app::{App, HomeInputState};
 /// * `rect` - A rectangle to insert widgets.
 /// * `app` - The main application.
 pub fn user_profile(f: &mut Frame, rect: Rect, app: &mut App) {
+    let theme = &app.theme;
     let chunks = Layout::default()
         .direction(Direction::Horizontal)
         .constraints([Constraint::Min(0), Constraint::Length(45)])
@@ -23,12 +27,12 @@ pub fn user_profile(f: &mut Frame, rect: Rect, app: &mut App) {
 
     match app.user_data {
         Some(ref user) => {
-            let style = Style::default().fg(Color::White).bold();
+            let style = Style::default().fg(theme.text).bold();
             let span = Span::styled(user.username.to_uppercase(), style);
             f.render_widget(Paragraph::new(span), chunks[0]);
         }
         None => {
-            let span = Span::styled("OFFLINE", Style::default().fg(Color::Rgb(255, 45, 85)));
+            let span = Span::styled("OFFLINE", Style::default().fg(theme.error));
             let paragraph = Paragraph::new(span).alignment(Alignment::Right);
             f.render_widget(paragraph, chunks[1]);
         }
@@ -42,10 +46,11 @@ pub fn user_profile(f: &mut Frame, rect: Rect, app: &mut App) {
 /// * `rect` - A rectangle to insert widgets.
 /// * `app` - The main application.
 pub fn search_bar(f: &mut Frame, rect: Rect, app: &mut App) {
+    let theme = &app.theme;
     let color = if matches!(app.home_input_state, HomeInputState::Searching) {
-        Color::Rgb(0, 255, 150)
+        theme.accent
     } else {
-        Color::Rgb(100, 100, 100)
+        theme.border
     };
 
     let border_style = Style::default().fg(color);
@@ -56,15 +61,26 @@ pub fn search_bar(f: &mut Frame, rect: Rect, app: &mut App) {
     let display_text = if app.search_bar_input.is_empty()
         && matches!(app.home_input_state, HomeInputState::Normal)
     {
-        "  Type '/' to search...".fg(Color::Gray)
+        "  Type '/' to search...".fg(theme.subtle)
     } else {
         format!("  {}", app.search_bar_input)
-            .fg(Color::Rgb(0, 255, 150))
+            .fg(theme.accent)
             .bold()
     };
 
     f.render_widget(Paragraph::new(display_text).block(block), rect);
 
+    if let Some(difficulty) = app.difficulty_filter {
+        let color = match difficulty {
+            Difficulty::Easy => theme.easy,
+            Difficulty::Medium => theme.medium,
+            Difficulty::Hard => theme.hard,
+        };
+
+        let label = format!("{difficulty:?} only ").fg(color);
+        f.render_widget(Paragraph::new(label).alignment(Alignment::Right), rect);
+    }
+
     if matches!(app.home_input_state, HomeInputState::Searching) {
         f.set_cursor_position((rect.x + app.search_bar_input.len() as u16 + 3, rect.y));
     }
@@ -76,32 +92,33 @@ pub fn search_bar(f: &mut Frame, rect: Rect, app: &mut App) {
 /// * `f` - The frame to render the widgets.
 /// * `rect` - A rectangle to insert widgets.
 /// * `app` - The main application.
-pub fn daily_challenge(f: &mut Frame, rect: Rect, app: &App) {
+pub fn daily_challenge(f: &mut Frame, rect: Rect, app: &mut App) {
+    let theme = &app.theme;
     let block = Block::default()
         .borders(Borders::ALL)
         .title(" DAILY CHALLENGE ")
-        .border_style(Style::default().fg(Color::Rgb(255, 160, 80)));
+        .border_style(Style::default().fg(theme.warning));
 
     match &app.daily_challenge {
         Some(p) => {
             let style = Style::default();
             let title_style = match p.status {
-                Some(ProblemStatus::Accepted) => style.fg(Color::DarkGray).italic(),
-                Some(ProblemStatus::Attempted) => style.fg(Color::Rgb(255, 160, 80)),
-                _ => style.fg(Color::White),
+                Some(ProblemStatus::Accepted) => style.fg(theme.accepted).italic(),
+                Some(ProblemStatus::Attempted) => style.fg(theme.attempted),
+                _ => style.fg(theme.text),
             };
 
             let diff_style = match p.difficulty {
-                Difficulty::Easy => Style::default().fg(Color::White),
-                Difficulty::Medium => Style::default().fg(Color::Gray),
-                Difficulty::Hard => Style::default().fg(Color::DarkGray),
+                Difficulty::Easy => Style::default().fg(theme.easy),
+                Difficulty::Medium => Style::default().fg(theme.medium),
+                Difficulty::Hard => Style::default().fg(theme.hard),
             };
 
             let row = Row::new(vec![
-                Cell::from(format!(" {}", p.frontend_question_id)).fg(Color::DarkGray),
+                Cell::from(format!(" {}", p.frontend_question_id)).fg(theme.muted),
                 Cell::from(p.title.clone()).style(title_style),
                 Cell::from(format!("{:?}", p.difficulty)).style(diff_style),
-                Cell::from(format!("{:.1}%", p.ac_rate)).fg(Color::DarkGray),
+                Cell::from(format!("{:.1}%", p.ac_rate)).fg(theme.muted),
             ])
             .height(1);
 
@@ -117,6 +134,7 @@ pub fn daily_challenge(f: &mut Frame, rect: Rect, app: &App) {
             .block(block);
 
             f.render_widget(table, rect);
+            app.mouse_targets.push((rect, MouseTarget::Daily));
         }
         None => {
             f.render_widget(Paragraph::new(" Loading...").block(block), rect);
@@ -131,7 +149,8 @@ pub fn daily_challenge(f: &mut Frame, rect: Rect, app: &App) {
 /// * `rect` - A rectangle to insert widgets.
 /// * `app` - The main application.
 pub fn problem_list(f: &mut Frame, rect: Rect, app: &mut App) {
-    let header_style = Style::default().fg(Color::Rgb(100, 100, 100)).bold();
+    let theme = &app.theme;
+    let header_style = Style::default().fg(theme.border).bold();
     let header = Row::new(vec!["ID", "TITLE", "DIFFICULTY", "STATUS"])
         .style(header_style)
         .height(1)
@@ -139,7 +158,7 @@ pub fn problem_list(f: &mut Frame, rect: Rect, app: &mut App) {
 
     let rows = app.problems.iter().enumerate().map(|(i, p)| {
         let bg = if i % 2 == 0 {
-            Color::Rgb(50, 50, 50)
+            theme.surface
         } else {
             Color::Reset
         };
@@ -154,10 +173,10 @@ pub fn problem_list(f: &mut Frame, rect: Rect, app: &mut App) {
 
         let style = Style::default();
         let title_style = match p.status {
-            Some(ProblemStatus::Accepted) => style.fg(Color::DarkGray).italic(),
-            _ if is_locked => style.fg(Color::Rgb(80, 80, 80)),
-            Some(ProblemStatus::Attempted) => style.fg(Color::Rgb(255, 160, 80)),
-            _ => style.fg(Color::White),
+            Some(ProblemStatus::Accepted) => style.fg(theme.accepted).italic(),
+            _ if is_locked => style.fg(theme.locked),
+            Some(ProblemStatus::Attempted) => style.fg(theme.attempted),
+            _ => style.fg(theme.text),
         };
 
         let title_content = if p.paid_only {
@@ -168,18 +187,18 @@ pub fn problem_list(f: &mut Frame, rect: Rect, app: &mut App) {
 
         let style = Style::default();
         let diff_style = match p.difficulty {
-            Difficulty::Easy => style.fg(Color::White),
-            Difficulty::Medium => style.fg(Color::Gray),
-            Difficulty::Hard => style.fg(Color::DarkGray),
+            Difficulty::Easy => style.fg(theme.easy),
+            Difficulty::Medium => style.fg(theme.medium),
+            Difficulty::Hard => style.fg(theme.hard),
         };
 
         let row_style = Style::default().bg(bg);
 
         Row::new(vec![
-            Cell::from(format!(" {}", p.frontend_question_id).fg(Color::DarkGray)),
+            Cell::from(format!(" {}", p.frontend_question_id).fg(theme.muted)),
             Cell::from(title_content).style(title_style),
             Cell::from(format!("{:?}", p.difficulty)).style(diff_style),
-            Cell::from(format!("{:.1}%", p.ac_rate)).fg(Color::DarkGray),
+            Cell::from(format!("{:.1}%", p.ac_rate)).fg(theme.muted),
         ])
         .style(row_style)
     });
@@ -188,10 +207,11 @@ pub fn problem_list(f: &mut Frame, rect: Rect, app: &mut App) {
     let total_items = app.problems.len();
     let visible_rows = rect.height.saturating_sub(1) as usize;
     let mut offset = app.problem_table_state.offset();
-    let scrolloff = 2;
+    // Short lists can't keep rows around the cursor without hiding it.
+    let scrolloff = 2.min(visible_rows.saturating_sub(1) / 2);
 
-    if selected >= offset + visible_rows - scrolloff {
-        offset = selected.saturating_sub(visible_rows - scrolloff - 1);
+    if selected + scrolloff >= offset + visible_rows {
+        offset = (selected + scrolloff + 1).saturating_sub(visible_rows);
     }
 
     if selected < offset + scrolloff {
@@ -202,10 +222,7 @@ pub fn problem_list(f: &mut Frame, rect: Rect, app: &mut App) {
     offset = offset.min(max_offset);
     *app.problem_table_state.offset_mut() = offset;
 
-    let highligh_style = Style::default()
-        .bg(Color::Rgb(60, 60, 60))
-        .fg(Color::Rgb(0, 255, 150))
-        .bold();
+    let highligh_style = Style::default().bg(theme.selection).fg(theme.accent).bold();
 
     let mut table = Table::new(
         rows,
@@ -218,13 +235,21 @@ pub fn problem_list(f: &mut Frame, rect: Rect, app: &mut App) {
     )
     .header(header)
     .highlight_spacing(HighlightSpacing::Always)
-    .highlight_symbol("▎".set_style(Color::Rgb(100, 100, 100)));
+    .highlight_symbol("▎".set_style(theme.border));
 
     if matches!(app.home_input_state, HomeInputState::Normal) {
         table = table.row_highlight_style(highligh_style);
     }
 
     f.render_stateful_widget(table, rect, &mut app.problem_table_state);
+
+    app.mouse_targets.push((rect, MouseTarget::ProblemList));
+    let offset = app.problem_table_state.offset();
+    let rows = (offset..total_items).take(visible_rows);
+    for (row, i) in rows.enumerate() {
+        let area = Rect::new(rect.x, rect.y + 1 + row as u16, rect.width, 1);
+        app.mouse_targets.push((area, MouseTarget::Problem(i)));
+    }
 }
 
 /// Renders the controls into the given frame.
@@ -234,49 +259,76 @@ pub fn problem_list(f: &mut Frame, rect: Rect, app: &mut App) {
 /// * `rect` - A rectangle to insert widgets.
 /// * `app` - The main application.
 pub fn home_controls(f: &mut Frame, rect: Rect, app: &mut App) {
-    let chunks = Layout::default()
-        .direction(Direction::Horizontal)
-        .constraints([Constraint::Length(20), Constraint::Min(0)])
-        .split(rect);
+    let theme = &app.theme;
+    let rect = utils::render_status(f, rect, app);
+
+    let keys_style = Style::default().fg(theme.subtle);
+    let desc_style = Style::default().fg(theme.muted);
 
-    if let Some(..) = app.error_message {
-        let err_text = format!("! ERROR");
-        let style = Style::default().fg(Color::Rgb(255, 45, 85));
-        let span = Span::styled(err_text, style);
-        let paragraph = Paragraph::new(span);
-        f.render_widget(paragraph, chunks[0]);
-    } else if app.is_loading {
-        let spinner = ["⠋", "⠙", "⠹", "⠸", "⠼", "⠴", "⠦", "⠧", "⠇", "⠏"];
-        let frame = spinner[app.spinner_index % spinner.len()];
-        let loading_text = format!(" {frame} FETCHING");
-        let style = Style::default().fg(Color::Rgb(0, 255, 150));
-        let span = Span::styled(loading_text, style);
-        let paragraph = Paragraph::new(span);
-        f.render_widget(paragraph, chunks[0]);
+    if let Some(scope) = app.key_scope() {
+        utils::render_key_help(f, rect, app, scope);
+        return;
     }
 
-    let keys_style = Style::default().fg(Color::Gray);
-    let desc_style = Style::default().fg(Color::DarkGray);
-
-    let current_keys = match app.home_input_state {
-        HomeInputState::Normal => Line::from(vec![
-            Span::styled("esc ", keys_style),
-            Span::styled("QUIT  ", desc_style),
-            Span::styled("jk ", keys_style),
-            Span::styled("MOVE  ", desc_style),
-            Span::styled("d ", keys_style),
-            Span::styled("DAILY  ", desc_style),
-            Span::styled("enter ", keys_style),
-            Span::styled("SELECT  ", desc_style),
-        ]),
-        HomeInputState::Searching => Line::from(vec![
-            Span::styled("esc ", keys_style),
-            Span::styled("CANCEL  ", desc_style),
-            Span::styled("enter ", keys_style),
-            Span::styled("CONFIRM  ", desc_style),
-        ]),
-    };
+    let current_keys = Line::from(vec![
+        Span::styled("esc ", keys_style),
+        Span::styled("CANCEL  ", desc_style),
+        Span::styled("f1 ", keys_style),
+        Span::styled("HELP  ", desc_style),
+        Span::styled("enter ", keys_style),
+        Span::styled("CONFIRM  ", desc_style),
+    ]);
 
     let help = Paragraph::new(current_keys).alignment(Alignment::Center);
     f.render_widget(help, rect);
 }
+
+/// Renders the list of suspended editors over the home screen.
+///
+/// # Arguments
+/// * `f` - The frame to render the widgets.
+/// * `rect` - The area to center the list in.
+/// * `app` - The main application.
+pub fn editor_sessions(f: &mut Frame, rect: Rect, app: &App) {
+    let theme = &app.theme;
+    let HomeInputState::Sessions { exiting } = app.home_input_state else {
+        return;
+    };
+
+    let area = utils::centered_rect(50, 40, rect);
+    let title = match exiting {
+        true => " Suspended editors are still open ",
+        false => " Editor sessions ",
+    };
+
+    let block = Block::bordered()
+        .title(title)
+        .title_alignment(Alignment::Center)
+        .border_style(Style::default().fg(theme.warning))
+        .padding(Padding::horizontal(1));
+
+    f.render_widget(Clear, area);
+
+    let inner = block.inner(area);
+    f.render_widget(block, area);
+
+    if app.editor_sessions.is_empty() {
+        utils::render_empty_background(f, inner, " NO SUSPENDED EDITORS ", theme);
+        return;
+    }
+
+    let rows = app.editor_sessions.iter().map(|(slug, session)| {
+        Row::new(vec![
+            Cell::from(slug.as_str()),
+            Cell::from(session.lang.to_string()).style(Style::default().fg(theme.muted)),
+        ])
+    });
+
+    let table = Table::new(rows, [Constraint::Min(0), Constraint::Length(12)])
+        .row_highlight_style(Style::default().fg(theme.accent).bold())
+        .highlight_symbol("> ")
+        .highlight_spacing(HighlightSpacing::Always);
+
+    let mut state = TableState::default().with_selected(app.session_index);
+    f.render_stateful_widget(table, inner, &mut state);
+}
@@ -1,7 +1,15 @@
+mod document;
 mod editor;
+mod help;
+mod highlight;
 mod home;
+mod palette;
+mod text;
 mod utils;
 
 pub use editor::*;
+pub use help::*;
+pub use highlight::{highlight, syntax_theme_exists};
 pub use home::*;
-pub use utils::*;
+pub use palette::*;
+pub use utils::too_small;
@@ -0,0 +1,107 @@
+use ratatui::{
+    Frame,
+    layout::{Alignment, Constraint, Direction, Layout, Rect},
+    style::Style,
+    text::{Line, Span},
+    widgets::{Block, Cell, Clear, HighlightSpacing, Padding, Paragraph, Row, Table, TableState},
+};
+
+use unicode_width::UnicodeWidthStr;
+
+use super::utils;
+use crate::app::{App, MouseTarget};
+
+/// Renders the command palette over the rest of the interface, if it's open.
+///
+/// # Arguments
+/// * `f` - The frame to render the widgets.
+/// * `rect` - The area to center the palette in.
+/// * `app` - The main application.
+pub fn command_palette(f: &mut Frame, rect: Rect, app: &mut App) {
+    let theme = &app.theme;
+    let Some(ref palette) = app.palette else {
+        return;
+    };
+
+    let keys_style = Style::default().fg(theme.subtle);
+    let desc_style = Style::default().fg(theme.muted);
+    let hints = Line::from(vec![
+        Span::styled(" esc ", keys_style),
+        Span::styled("CLOSE  ", desc_style),
+        Span::styled("enter ", keys_style),
+        Span::styled("RUN  ", desc_style),
+        Span::styled("↑↓ ", keys_style),
+        Span::styled("SELECT ", desc_style),
+    ]);
+
+    let area = utils::centered_rect(60, 50, rect);
+    let block = Block::bordered()
+        .title(" Commands ")
+        .title_alignment(Alignment::Center)
+        .title_bottom(hints.centered())
+        .border_style(Style::default().fg(theme.accent))
+        .padding(Padding::horizontal(1));
+
+    f.render_widget(Clear, area);
+
+    let inner = block.inner(area);
+    f.render_widget(block, area);
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(1), // input
+            Constraint::Length(1), // padding
+            Constraint::Min(0),    // entries
+        ])
+        .split(inner);
+
+    let input = Line::from(vec![
+        Span::styled("> ", Style::default().fg(theme.accent)),
+        Span::styled(
+            palette.input.as_str(),
+            Style::default().fg(theme.text).bold(),
+        ),
+    ]);
+    f.render_widget(Paragraph::new(input), chunks[0]);
+    f.set_cursor_position((chunks[0].x + palette.input.width() as u16 + 2, chunks[0].y));
+
+    if palette.visible().is_empty() {
+        utils::render_empty_background(f, chunks[2], " NO MATCHING COMMANDS ", theme);
+        return;
+    }
+
+    let rows = palette.visible().iter().map(|entry| {
+        Row::new(vec![
+            Cell::from(entry.label.as_str()),
+            Cell::from(Line::from(entry.keys.as_deref().unwrap_or_default()).right_aligned())
+                .style(Style::default().fg(theme.muted)),
+        ])
+    });
+
+    let keys_width = palette
+        .visible()
+        .iter()
+        .filter_map(|entry| entry.keys.as_ref())
+        .map(|keys| keys.chars().count())
+        .max()
+        .unwrap_or_default();
+
+    let table = Table::new(
+        rows,
+        [Constraint::Min(0), Constraint::Length(keys_width as u16)],
+    )
+    .style(Style::default().fg(theme.subtle))
+    .row_highlight_style(Style::default().fg(theme.accent).bold())
+    .highlight_symbol("> ")
+    .highlight_spacing(HighlightSpacing::Always);
+
+    let mut state = TableState::default().with_selected(palette.selected);
+    f.render_stateful_widget(table, chunks[2], &mut state);
+
+    let entries = (state.offset()..palette.visible().len()).take(chunks[2].height as usize);
+    for (row, i) in entries.enumerate() {
+        let area = Rect::new(chunks[2].x, chunks[2].y + row as u16, chunks[2].width, 1);
+        app.mouse_targets.push((area, MouseTarget::PaletteEntry(i)));
+    }
+}
@@ -0,0 +1,311 @@
+use ratatui::{
+    style::Style,
+    text::{Line, Span, Text},
+};
+use unicode_width::{UnicodeWidthChar, UnicodeWidthStr};
+
+/// A prefix repeated at the start of every line of a block, like a bullet or a quote bar.
+struct Indent {
+    first: String,
+    rest: String,
+    style: Style,
+    used: bool,
+}
+
+/// Builds styled text wrapped to a fixed width, one block element at a time.
+///
+/// Since the output is already wrapped, the amount of lines it has is
+/// exactly the amount of rows it takes on screen.
+pub struct TextBuilder {
+    width: usize,
+    lines: Vec<Line<'static>>,
+    line: Vec<Span<'static>>,
+    line_width: usize,
+    has_content: bool,
+    styles: Vec<Style>,
+    indents: Vec<Indent>,
+    fill: Option<Style>,
+    pending_space: bool,
+    pending_gap: bool,
+}
+
+impl TextBuilder {
+    /// Creates a new `TextBuilder`.
+    ///
+    /// # Arguments
+    /// * `width` - The amount of columns the text has to fit in.
+    pub fn new(width: u16) -> Self {
+        Self {
+            width: (width as usize).max(1),
+            lines: Vec::new(),
+            line: Vec::new(),
+            line_width: 0,
+            has_content: false,
+            styles: Vec::new(),
+            indents: Vec::new(),
+            fill: None,
+            pending_space: false,
+            pending_gap: false,
+        }
+    }
+
+    /// Returns the style of the text being written, combining every pushed style.
+    fn style(&self) -> Style {
+        self.styles
+            .iter()
+            .fold(Style::default(), |acc, style| acc.patch(*style))
+    }
+
+    /// Applies a style to the text written until it's popped.
+    pub fn push_style(&mut self, style: Style) {
+        self.styles.push(style);
+    }
+
+    /// Removes the last pushed style.
+    pub fn pop_style(&mut self) {
+        self.styles.pop();
+    }
+
+    /// Prefixes the following lines, using `first` for the first one and `rest` afterwards.
+    pub fn push_indent(&mut self, first: &str, rest: &str, style: Style) {
+        self.indents.push(Indent {
+            first: first.to_string(),
+            rest: rest.to_string(),
+            style,
+            used: false,
+        });
+    }
+
+    /// Removes the last pushed indentation.
+    pub fn pop_indent(&mut self) {
+        self.indents.pop();
+    }
+
+    /// Fills the following lines up to the full width, e.g. to give code blocks a background.
+    pub fn set_fill(&mut self, fill: Option<Style>) {
+        self.fill = fill;
+    }
+
+    /// Writes a span as is, merging it with the previous one if they share a style.
+    fn push_span(&mut self, content: &str, style: Style) {
+        self.line_width += content.width();
+
+        match self.line.last_mut() {
+            Some(last) if last.style == style => last.content.to_mut().push_str(content),
+            _ => self.line.push(Span::styled(content.to_string(), style)),
+        }
+    }
+
+    /// Starts a line if needed, writing the separation from the previous
+    /// block and the indentation prefixes.
+    fn begin_line(&mut self) {
+        if self.has_content {
+            return;
+        }
+
+        if self.pending_gap && !self.lines.is_empty() {
+            let fill = self.fill.take();
+            self.write_prefixes(false);
+            self.end_line();
+            self.fill = fill;
+        }
+
+        self.pending_gap = false;
+        self.write_prefixes(true);
+        self.has_content = true;
+    }
+
+    /// Writes the prefix of every indentation level.
+    ///
+    /// # Arguments
+    /// * `starting` - Whether the line starts new content, rather than separating
+    ///   blocks. Separating lines skip the levels that haven't started yet.
+    fn write_prefixes(&mut self, starting: bool) {
+        for i in 0..self.indents.len() {
+            let indent = &self.indents[i];
+            let prefix = match (indent.used, starting) {
+                (true, _) => indent.rest.clone(),
+                (false, true) => indent.first.clone(),
+                (false, false) => continue,
+            };
+
+            let style = indent.style;
+            self.indents[i].used |= starting;
+            self.push_span(&prefix, style);
+        }
+    }
+
+    /// Closes the current line, filling it if requested.
+    fn end_line(&mut self) {
+        if let Some(fill) = self.fill {
+            let padding = self.width.saturating_sub(self.line_width);
+            self.push_span(&" ".repeat(padding), fill);
+        }
+
+        self.lines.push(Line::from(std::mem::take(&mut self.line)));
+        self.line_width = 0;
+        self.has_content = false;
+        self.pending_space = false;
+    }
+
+    /// Writes text, wrapping it at word boundaries.
+    pub fn text(&mut self, text: &str) {
+        let style = self.style();
+
+        for word in text.split_inclusive(char::is_whitespace) {
+            let trimmed = word.trim_end_matches(char::is_whitespace);
+            let trailing_space = trimmed.len() < word.len();
+
+            if !trimmed.is_empty() {
+                self.word(trimmed, style);
+            }
+
+            if trailing_space && self.has_content {
+                self.pending_space = true;
+            }
+        }
+    }
+
+    /// Writes a single word, moving it to the next line if it doesn't fit.
+    fn word(&mut self, word: &str, style: Style) {
+        self.begin_line();
+
+        let space = usize::from(self.pending_space);
+        let word_width = word.width();
+
+        if self.line_width + space + word_width > self.width
+            && self.line_width > self.prefix_width()
+        {
+            self.end_line();
+            self.begin_line();
+        } else if self.pending_space {
+            self.push_span(" ", style);
+        }
+
+        self.pending_space = false;
+
+        if self.line_width + word_width <= self.width {
+            self.push_span(word, style);
+        } else {
+            self.hard_wrap(word, style);
+        }
+    }
+
+    /// Writes text breaking it at any character once a line is full.
+    fn hard_wrap(&mut self, text: &str, style: Style) {
+        let mut chunk = String::new();
+
+        for ch in text.chars() {
+            let ch_width = ch.width().unwrap_or_default();
+            if self.line_width + chunk.width() + ch_width > self.width && !chunk.is_empty() {
+                self.push_span(&chunk, style);
+                chunk.clear();
+                self.end_line();
+                self.begin_line();
+            }
+
+            chunk.push(ch);
+        }
+
+        self.push_span(&chunk, style);
+    }
+
+    /// Returns the width taken by the indentation of the current line.
+    fn prefix_width(&self) -> usize {
+        self.indents
+            .iter()
+            .map(|indent| indent.rest.width().max(indent.first.width()))
+            .sum()
+    }
+
+    /// Writes lines of code as they are, layering their own styles over the current one.
+    pub fn highlighted(&mut self, lines: &[Line<'static>]) {
+        let style = self.style();
+        let mut lines = lines.iter().peekable();
+
+        while let Some(line) = lines.next() {
+            self.begin_line();
+            for span in &line.spans {
+                self.hard_wrap(&span.content, style.patch(span.style));
+            }
+
+            if lines.peek().is_some() {
+                self.end_line();
+            }
+        }
+    }
+
+    /// Ends the current line.
+    pub fn newline(&mut self) {
+        self.begin_line();
+        self.end_line();
+    }
+
+    /// Ends the current line if anything was written on it.
+    pub fn newline_if_started(&mut self) {
+        if self.has_content {
+            self.end_line();
+        }
+    }
+
+    /// Ends the current block, leaving a blank line before the next one.
+    pub fn end_block(&mut self) {
+        if self.has_content {
+            self.end_line();
+        }
+
+        self.pending_gap = true;
+    }
+
+    /// Returns the index of the line the next text will be written on.
+    pub fn next_line(&self) -> usize {
+        match self.has_content {
+            true => self.lines.len(),
+            false => self.lines.len() + usize::from(self.pending_gap && !self.lines.is_empty()),
+        }
+    }
+
+    /// Leaves blank rows as a block of their own, e.g. to draw an image over them.
+    ///
+    /// # Returns
+    /// The line and column where the rows start.
+    pub fn reserve(&mut self, rows: u16) -> (usize, usize) {
+        self.end_block();
+
+        let line = self.next_line();
+        let mut column = 0;
+
+        for _ in 0..rows {
+            self.begin_line();
+            column = self.line_width;
+            self.end_line();
+        }
+
+        self.end_block();
+        (line, column)
+    }
+
+    /// Writes a horizontal rule across the whole width.
+    pub fn rule(&mut self, style: Style) {
+        self.end_block();
+        self.begin_line();
+
+        let width = self.width.saturating_sub(self.line_width);
+        self.push_span(&"─".repeat(width), style);
+        self.end_block();
+    }
+
+    /// Returns the amount of columns left for content after the indentation.
+    pub fn available_width(&self) -> usize {
+        self.width.saturating_sub(self.prefix_width())
+    }
+
+    /// Finishes the text.
+    pub fn finish(mut self) -> Text<'static> {
+        if self.has_content {
+            self.end_line();
+        }
+
+        Text::from(self.lines)
+    }
+}
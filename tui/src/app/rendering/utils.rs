@@ -1,14 +1,14 @@
 use ratatui::{
     Frame,
-    layout::{Constraint, Direction, Layout, Rect},
-    style::{Color, Style, Stylize},
-    text::Text,
+    layout::{Alignment, Constraint, Direction, Layout, Rect},
+    style::{Style, Stylize},
+    text::{Line, Span},
     widgets::{Clear, Paragraph},
 };
 
-pub fn markdown_to_text(md: &str) -> Text<'_> {
-    md.into()
-}
+use unicode_width::UnicodeWidthStr;
+
+use crate::app::{App, MouseTarget, keymap::Scope, theme::Theme};
 
 pub fn centered_rect(percent_x: u16, percent_y: u16, r: Rect) -> Rect {
     let popup_layout = Layout::default()
@@ -30,8 +30,97 @@ pub fn centered_rect(percent_x: u16, percent_y: u16, r: Rect) -> Rect {
         .split(popup_layout[1])[1]
 }
 
-pub fn render_empty_background(frame: &mut Frame, area: Rect, text: &str) {
-    let style = Style::default().fg(Color::DarkGray).dim().bold();
+/// Renders the help bar of a scope from the keys bound to its commands,
+/// making the entries that stand for a single command clickable.
+///
+/// # Arguments
+/// * `f` - The frame to render the widgets.
+/// * `rect` - The area to center the help bar in.
+/// * `app` - The main application.
+/// * `scope` - The scope whose bindings are shown.
+pub fn render_key_help(f: &mut Frame, rect: Rect, app: &mut App, scope: Scope) {
+    let keys_style = Style::default().fg(app.theme.subtle);
+    let desc_style = Style::default().fg(app.theme.muted);
+
+    let entries: Vec<_> = scope
+        .help()
+        .iter()
+        .filter(|(commands, _)| commands.iter().any(|cmd| app.command_available(*cmd)))
+        .filter_map(|(commands, desc)| {
+            let keys = app.keymap.describe(scope, commands)?;
+            Some((*commands, keys, *desc))
+        })
+        .collect();
+
+    let line = Line::from(
+        entries
+            .iter()
+            .flat_map(|(_, keys, desc)| {
+                [
+                    Span::styled(format!("{keys} "), keys_style),
+                    Span::styled(format!("{desc}  "), desc_style),
+                ]
+            })
+            .collect::<Vec<_>>(),
+    );
+
+    let mut x = rect.x + rect.width.saturating_sub(line.width() as u16) / 2;
+    for (commands, keys, desc) in &entries {
+        let width = (keys.chars().count() + desc.chars().count() + 1) as u16;
+        let area = Rect::new(x, rect.y, width, 1).intersection(rect);
+
+        if let [command] = commands {
+            app.mouse_targets
+                .push((area, MouseTarget::Command(*command)));
+        }
+
+        x += width + 2;
+    }
+
+    f.render_widget(Paragraph::new(line).alignment(Alignment::Center), rect);
+}
+
+/// Renders the error message at the left of the controls if there's one, or
+/// a spinner while something is loading.
+///
+/// # Arguments
+/// * `f` - The frame to render the widgets.
+/// * `rect` - The controls row.
+/// * `app` - The main application.
+///
+/// # Returns
+/// The area left for the help bar.
+pub fn render_status(f: &mut Frame, rect: Rect, app: &App) -> Rect {
+    let theme = &app.theme;
+
+    if let Some(ref err) = app.error_message {
+        let message = format!("! {}", err.replace('\n', " "));
+
+        // The message takes at most half of the row so the help bar stays visible.
+        let width = (message.width() as u16 + 2).min(rect.width / 2);
+        let [status, rest] =
+            Layout::horizontal([Constraint::Length(width), Constraint::Min(0)]).areas(rect);
+
+        let paragraph = Paragraph::new(Span::styled(message, Style::default().fg(theme.error)));
+        f.render_widget(paragraph, status);
+        return rest;
+    }
+
+    if app.is_loading {
+        let spinner = ["⠋", "⠙", "⠹", "⠸", "⠼", "⠴", "⠦", "⠧", "⠇", "⠏"];
+        let frame = spinner[app.spinner_index % spinner.len()];
+        let span = Span::styled(
+            format!(" {frame} FETCHING"),
+            Style::default().fg(theme.accent),
+        );
+        f.render_widget(Paragraph::new(span), rect);
+    }
+
+    rect
+}
+
+pub fn render_empty_background(frame: &mut Frame, area: Rect, text: &str, theme: &Theme) {
+    let style = Style::default().fg(theme.muted).dim().bold();
     let buf = frame.buffer_mut();
 
     for y in area.top()..area.bottom() {
@@ -54,6 +143,46 @@ pub fn render_empty_background(frame: &mut Frame, area: Rect, text: &str) {
     let message_area = Rect::new(x, y, area_w, area_h);
     frame.render_widget(Clear, message_area);
 
-    let paragraph = Paragraph::new(text.fg(Color::DarkGray).dim().bold());
+    let paragraph = Paragraph::new(text.fg(theme.muted).dim().bold());
     frame.render_widget(paragraph, Rect::new(x + h_padding, y + 1, area_w, 1));
 }
+
+/// Renders a notice in place of the interface when the terminal is smaller
+/// than it needs.
+///
+/// # Arguments
+/// * `frame` - The frame to render the widgets.
+/// * `area` - The whole terminal.
+/// * `min` - The smallest width and height the interface is drawn in.
+/// * `theme` - The colors to draw with.
+pub fn too_small(frame: &mut Frame, area: Rect, min: (u16, u16), theme: &Theme) {
+    let (min_width, min_height) = min;
+    let size_style = |fits| match fits {
+        true => Style::default().fg(theme.accent),
+        false => Style::default().fg(theme.error),
+    };
+
+    let lines = vec![
+        Line::styled(
+            "Terminal too small",
+            Style::default().fg(theme.warning).bold(),
+        ),
+        Line::from(vec![
+            Span::styled("now ", Style::default().fg(theme.muted)),
+            Span::styled(area.width.to_string(), size_style(area.width >= min_width)),
+            Span::styled("×", Style::default().fg(theme.muted)),
+            Span::styled(
+                area.height.to_string(),
+                size_style(area.height >= min_height),
+            ),
+            Span::styled(
+                format!(", needs {min_width}×{min_height}"),
+                Style::default().fg(theme.muted),
+            ),
+        ]),
+    ];
+
+    let y = area.y + area.height.saturating_sub(lines.len() as u16) / 2;
+    let rect = Rect::new(area.x, y, area.width, area.height.min(lines.len() as u16));
+    frame.render_widget(Paragraph::new(lines).alignment(Alignment::Center), rect);
+}
@@ -0,0 +1,305 @@
+use std::{env, str::FromStr};
+
+use ratatui::style::Color;
+use serde::{Deserialize, Deserializer, de};
+
+/// The names of the themes that ship with leetui.
+pub const THEMES: &[&str] = &["dark", "light", "high-contrast"];
+
+/// The colors every part of the interface is drawn with, named by what
+/// they mean rather than how they look.
+#[derive(Debug, Clone)]
+pub struct Theme {
+    pub name: String,
+    /// Regular text, like problem titles.
+    pub text: Color,
+    /// Secondary text, like the keys of the help bar.
+    pub subtle: Color,
+    /// Text that should stay out of the way, like ids and inactive borders.
+    pub muted: Color,
+    /// Whatever has the focus, like headings, the selected row or a passed test.
+    pub accent: Color,
+    /// Focused panes, inline code and attention-worthy things.
+    pub warning: Color,
+    /// Failures and invalid input.
+    pub error: Color,
+    /// The borders and headers that frame the home screen.
+    pub border: Color,
+    /// The background of code, input boxes and every other row of the problem list.
+    pub surface: Color,
+    /// The background of the selected problem.
+    pub selection: Color,
+    /// Problems that need a premium subscription.
+    pub locked: Color,
+    pub easy: Color,
+    pub medium: Color,
+    pub hard: Color,
+    pub accepted: Color,
+    pub attempted: Color,
+    /// The syntect theme code is highlighted with, or `None` to leave it plain.
+    pub syntax: Option<String>,
+}
+
+impl Default for Theme {
+    fn default() -> Self {
+        Self::dark()
+    }
+}
+
+impl Theme {
+    /// The original colors, made for dark terminals.
+    pub fn dark() -> Self {
+        Self {
+            name: "dark".into(),
+            text: Color::White,
+            subtle: Color::Gray,
+            muted: Color::DarkGray,
+            accent: Color::Rgb(0, 255, 150),
+            warning: Color::Rgb(255, 160, 80),
+            error: Color::Rgb(255, 45, 85),
+            border: Color::Rgb(100, 100, 100),
+            surface: Color::Rgb(50, 50, 50),
+            selection: Color::Rgb(60, 60, 60),
+            locked: Color::Rgb(80, 80, 80),
+            easy: Color::White,
+            medium: Color::Gray,
+            hard: Color::DarkGray,
+            accepted: Color::DarkGray,
+            attempted: Color::Rgb(255, 160, 80),
+            syntax: Some("base16-eighties.dark".into()),
+        }
+    }
+
+    /// Darker colors that read well on light terminals.
+    pub fn light() -> Self {
+        Self {
+            name: "light".into(),
+            text: Color::Black,
+            subtle: Color::Rgb(80, 80, 80),
+            muted: Color::Rgb(135, 135, 135),
+            accent: Color::Rgb(0, 130, 85),
+            warning: Color::Rgb(185, 90, 0),
+            error: Color::Rgb(200, 20, 50),
+            border: Color::Rgb(160, 160, 160),
+            surface: Color::Rgb(232, 232, 232),
+            selection: Color::Rgb(210, 210, 210),
+            locked: Color::Rgb(185, 185, 185),
+            easy: Color::Black,
+            medium: Color::Rgb(80, 80, 80),
+            hard: Color::Rgb(135, 135, 135),
+            accepted: Color::Rgb(135, 135, 135),
+            attempted: Color::Rgb(185, 90, 0),
+            syntax: Some("InspiredGitHub".into()),
+        }
+    }
+
+    /// Bright, distinct colors from the terminal's own palette.
+    pub fn high_contrast() -> Self {
+        Self {
+            name: "high-contrast".into(),
+            text: Color::White,
+            subtle: Color::White,
+            muted: Color::Gray,
+            accent: Color::LightGreen,
+            warning: Color::LightYellow,
+            error: Color::LightRed,
+            border: Color::White,
+            surface: Color::Black,
+            selection: Color::Blue,
+            locked: Color::DarkGray,
+            easy: Color::LightGreen,
+            medium: Color::LightYellow,
+            hard: Color::LightRed,
+            accepted: Color::Gray,
+            attempted: Color::LightYellow,
+            syntax: Some("base16-ocean.dark".into()),
+        }
+    }
+
+    /// The terminal's default colors everywhere, for `NO_COLOR`.
+    pub fn no_color() -> Self {
+        Self {
+            name: "no-color".into(),
+            text: Color::Reset,
+            subtle: Color::Reset,
+            muted: Color::Reset,
+            accent: Color::Reset,
+            warning: Color::Reset,
+            error: Color::Reset,
+            border: Color::Reset,
+            surface: Color::Reset,
+            selection: Color::Reset,
+            locked: Color::Reset,
+            easy: Color::Reset,
+            medium: Color::Reset,
+            hard: Color::Reset,
+            accepted: Color::Reset,
+            attempted: Color::Reset,
+            syntax: None,
+        }
+    }
+
+    /// Returns one of the themes that ship with leetui by its name.
+    pub fn named(name: &str) -> Option<Self> {
+        match name {
+            "dark" => Some(Self::dark()),
+            "light" => Some(Self::light()),
+            "high-contrast" => Some(Self::high_contrast()),
+            _ => None,
+        }
+    }
+
+    /// Builds the theme chosen in the configuration, unless `NO_COLOR` is set.
+    ///
+    /// # Arguments
+    /// * `config` - The theme section of the configuration.
+    ///
+    /// # Returns
+    /// The theme, or an error naming the theme that doesn't exist.
+    pub fn from_config(config: &ThemeConfig) -> Result<Self, String> {
+        let theme = match config {
+            ThemeConfig::Name(name) => Self::named(name),
+            ThemeConfig::Custom(custom) => {
+                Self::named(&custom.base).map(|theme| custom.apply(theme))
+            }
+        };
+
+        let theme = theme.ok_or_else(|| {
+            format!(
+                "unknown theme {:?}, expected one of {}",
+                config.base(),
+                THEMES.join(", ")
+            )
+        })?;
+
+        match no_color() {
+            true => Ok(Self::no_color()),
+            false => Ok(theme),
+        }
+    }
+}
+
+/// Whether the user asked for no colors, following <https://no-color.org>.
+pub fn no_color() -> bool {
+    env::var_os("NO_COLOR").is_some_and(|value| !value.is_empty())
+}
+
+/// The theme section of the configuration, which is either the name of a
+/// theme or a table overriding some colors of one.
+#[derive(Debug)]
+pub enum ThemeConfig {
+    Name(String),
+    Custom(CustomTheme),
+}
+
+impl<'de> Deserialize<'de> for ThemeConfig {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        // Matched by hand instead of `#[serde(untagged)]`, which would hide why a table is invalid.
+        match toml::Value::deserialize(deserializer)? {
+            toml::Value::String(name) => Ok(Self::Name(name)),
+            value @ toml::Value::Table(_) => CustomTheme::deserialize(value)
+                .map(Self::Custom)
+                .map_err(de::Error::custom),
+            _ => Err(de::Error::custom("theme must be a name or a table")),
+        }
+    }
+}
+
+impl Default for ThemeConfig {
+    fn default() -> Self {
+        Self::Name("dark".into())
+    }
+}
+
+impl ThemeConfig {
+    /// Returns the name of the theme this one is based on.
+    fn base(&self) -> &str {
+        match self {
+            Self::Name(name) => name,
+            Self::Custom(custom) => &custom.base,
+        }
+    }
+}
+
+/// A theme based on one of the bundled ones, with some of its colors replaced.
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct CustomTheme {
+    #[serde(default = "default_base")]
+    base: String,
+    text: Option<ColorValue>,
+    subtle: Option<ColorValue>,
+    muted: Option<ColorValue>,
+    accent: Option<ColorValue>,
+    warning: Option<ColorValue>,
+    error: Option<ColorValue>,
+    border: Option<ColorValue>,
+    surface: Option<ColorValue>,
+    selection: Option<ColorValue>,
+    locked: Option<ColorValue>,
+    easy: Option<ColorValue>,
+    medium: Option<ColorValue>,
+    hard: Option<ColorValue>,
+    accepted: Option<ColorValue>,
+    attempted: Option<ColorValue>,
+    syntax: Option<String>,
+}
+
+fn default_base() -> String {
+    "dark".into()
+}
+
+impl CustomTheme {
+    /// Replaces the colors of a theme with the ones set in this one.
+    fn apply(&self, mut theme: Theme) -> Theme {
+        let overrides = [
+            (&mut theme.text, self.text),
+            (&mut theme.subtle, self.subtle),
+            (&mut theme.muted, self.muted),
+            (&mut theme.accent, self.accent),
+            (&mut theme.warning, self.warning),
+            (&mut theme.error, self.error),
+            (&mut theme.border, self.border),
+            (&mut theme.surface, self.surface),
+            (&mut theme.selection, self.selection),
+            (&mut theme.locked, self.locked),
+            (&mut theme.easy, self.easy),
+            (&mut theme.medium, self.medium),
+            (&mut theme.hard, self.hard),
+            (&mut theme.accepted, self.accepted),
+            (&mut theme.attempted, self.attempted),
+        ];
+
+        for (color, value) in overrides {
+            if let Some(ColorValue(value)) = value {
+                *color = value;
+            }
+        }
+
+        if let Some(ref syntax) = self.syntax {
+            theme.syntax = Some(syntax.clone());
+        }
+
+        theme.name = "custom".into();
+        theme
+    }
+}
+
+/// A color written like `red`, `#00ff96` or `236`.
+#[derive(Debug, Clone, Copy)]
+struct ColorValue(Color);
+
+impl<'de> Deserialize<'de> for ColorValue {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Color::from_str(&value)
+            .map(ColorValue)
+            .map_err(|_| de::Error::custom(format!("invalid color {value:?}")))
+    }
+}
@@ -1,10 +1,24 @@
-pub fn html_to_markdown(html: &str) -> String {
-    let cleaned_html = html
-        .replace("<sup>", "^")
-        .replace("</sup>", "")
-        .replace("<sub>", "_")
-        .replace("</sub>", "")
-        .replace("&nbsp;", " ");
+use std::{env, path::PathBuf};
 
-    html2md::parse_html(&cleaned_html)
+/// Returns the directory holding leetui's configuration, honoring `$XDG_CONFIG_HOME`.
+pub fn config_dir() -> PathBuf {
+    env::var_os("XDG_CONFIG_HOME")
+        .map(PathBuf::from)
+        .filter(|dir| dir.is_absolute())
+        .unwrap_or_else(|| env::home_dir().unwrap_or_default().join(".config"))
+        .join("leetui")
+}
+
+/// Returns the directory problem workspaces are created in unless configured otherwise.
+pub fn default_workspace_dir() -> PathBuf {
+    env::home_dir().unwrap_or_default().join(".leetui")
+}
+
+/// Returns the directory holding leetui's cached downloads, honoring `$XDG_CACHE_HOME`.
+pub fn cache_dir() -> PathBuf {
+    env::var_os("XDG_CACHE_HOME")
+        .map(PathBuf::from)
+        .filter(|dir| dir.is_absolute())
+        .unwrap_or_else(|| env::home_dir().unwrap_or_default().join(".cache"))
+        .join("leetui")
 }
@@ -0,0 +1,177 @@
+mod scaffold;
+mod support;
+mod template;
+mod test_cases;
+
+use std::{
+    fs, io,
+    path::{Path, PathBuf},
+    sync::OnceLock,
+};
+
+use api::{Language, Question};
+
+use crate::app::utils;
+
+pub use scaffold::write_scaffold;
+pub use support::write_support_files;
+pub use template::{
+    problem_url, render_solution, strip_local_only, with_description_header, with_language_marker,
+    with_package_clause,
+};
+pub use test_cases::{load_test_cases, remove_test_cases, save_test_cases};
+
+/// The directory where every problem workspace lives, set once from the configuration.
+static ROOT_DIR: OnceLock<PathBuf> = OnceLock::new();
+
+/// Sets the directory where every problem workspace lives.
+///
+/// Only the first call has an effect, which should happen before any workspace is used.
+pub fn set_root_dir(dir: PathBuf) {
+    let _ = ROOT_DIR.set(dir);
+}
+
+/// Returns the directory where every problem workspace lives, which is the
+/// default one if it wasn't set.
+pub fn root_dir() -> PathBuf {
+    ROOT_DIR.get_or_init(utils::default_workspace_dir).clone()
+}
+
+/// Returns the workspace directory of a problem.
+///
+/// # Arguments
+/// * `slug` - The slug identifier for the problem.
+pub fn problem_dir(slug: &str) -> PathBuf {
+    root_dir().join(slug)
+}
+
+/// Returns the path of the default solution file for a language.
+///
+/// # Arguments
+/// * `dir` - The workspace directory of the problem.
+/// * `ext` - The extension of the language.
+pub fn solution_path(dir: &Path, ext: &str) -> PathBuf {
+    dir.join(format!("solution.{ext}"))
+}
+
+/// Returns the path of a solution named by the user.
+///
+/// Go compiles every file of a directory into the same package, so the
+/// solutions after the default one get a directory of their own.
+///
+/// # Arguments
+/// * `dir` - The workspace directory of the problem.
+/// * `name` - The file name of the solution.
+/// * `lang` - The language of the solution.
+pub fn named_solution_path(dir: &Path, name: &str, lang: Language) -> PathBuf {
+    let default = solution_path(dir, lang.ext());
+    let path = dir.join(name);
+
+    match lang {
+        Language::Go if path != default => {
+            let stem = Path::new(name).file_stem().unwrap_or_default();
+            dir.join(stem).join(name)
+        }
+        _ => path,
+    }
+}
+
+/// Returns the language a solution file is written in, judging by its
+/// extension and the marker leetui leaves when that isn't enough.
+///
+/// # Arguments
+/// * `path` - The path of the file.
+pub fn solution_language(path: &Path) -> Option<Language> {
+    let ext = path.extension()?.to_str()?;
+    let lang = Language::from_ext(ext).filter(|lang| *lang != Language::Unknown)?;
+
+    let marked = fs::read_to_string(path)
+        .ok()
+        .and_then(|code| template::marked_language(&code))
+        .filter(|marked| marked.ext() == lang.ext());
+
+    Some(marked.unwrap_or(lang))
+}
+
+/// Lists the solutions in a problem's workspace, leaving out the files leetui
+/// writes around them.
+///
+/// # Arguments
+/// * `dir` - The workspace directory of the problem.
+///
+/// # Returns
+/// The paths of the solutions, sorted by name.
+pub fn list_solutions(dir: &Path) -> io::Result<Vec<PathBuf>> {
+    let mut paths = solutions_in(dir)?;
+
+    // Named Go solutions live in directories of their own, see `named_solution_path`.
+    for entry in fs::read_dir(dir)?.flatten() {
+        if entry.file_type()?.is_dir() {
+            let nested = solutions_in(&entry.path())?;
+            paths.extend(nested.into_iter().filter(|path| {
+                path.extension()
+                    .is_some_and(|ext| ext == Language::Go.ext())
+            }));
+        }
+    }
+
+    paths.sort();
+    Ok(paths)
+}
+
+/// Lists the solution files right inside a directory.
+///
+/// Project files are only left out for the languages solved in the
+/// directory, so a `lib.rs` next to Python solutions is still listed.
+fn solutions_in(dir: &Path) -> io::Result<Vec<PathBuf>> {
+    let files: Vec<_> = fs::read_dir(dir)?
+        .flatten()
+        .map(|entry| entry.path())
+        .filter(|path| path.is_file())
+        .filter_map(|path| {
+            let name = path.file_name()?.to_string_lossy().into_owned();
+            let lang = solution_language(&path)?;
+            (!support::is_support_file(&name)).then_some((path, name, lang))
+        })
+        .collect();
+
+    let solved: Vec<_> = files
+        .iter()
+        .filter(|(_, name, lang)| !scaffold::is_scaffold_file(name, *lang))
+        .map(|(_, _, lang)| *lang)
+        .collect();
+
+    let paths = files
+        .into_iter()
+        .filter(|(_, name, lang)| {
+            !solved.contains(lang) || !scaffold::is_scaffold_file(name, *lang)
+        })
+        .map(|(path, _, _)| path)
+        .collect();
+
+    Ok(paths)
+}
+
+/// Writes the description of a question as a `README.md` into its workspace,
+/// unless one already exists.
+///
+/// # Arguments
+/// * `dir` - The workspace directory of the problem.
+/// * `question` - The question being solved.
+pub fn write_readme(dir: &Path, question: &Question) -> io::Result<()> {
+    let path = dir.join("README.md");
+    if path.exists() {
+        return Ok(());
+    }
+
+    let contents = format!(
+        "# {}. {}\n\n**{:?}** · <{}>\n\n{}\n",
+        question.question_id,
+        question.title,
+        question.difficulty,
+        problem_url(&question.title_slug),
+        question.document().to_markdown().trim_end(),
+    );
+
+    fs::write(path, contents)
+}
@@ -0,0 +1,196 @@
+use std::{
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+use api::Language;
+
+use super::support;
+use crate::app::utils;
+
+/// A file of the minimal project built around a solution.
+///
+/// Its contents may use the `{{name}}`, `{{slug}}`, `{{solution}}` and
+/// `{{dir}}` placeholders, which are expanded when written.
+struct ScaffoldFile {
+    name: String,
+    contents: String,
+}
+
+const RUST: &[(&str, &str)] = &[
+    (
+        "Cargo.toml",
+        include_str!("../../../assets/scaffold/rust/Cargo.toml"),
+    ),
+    (
+        "lib.rs",
+        include_str!("../../../assets/scaffold/rust/lib.rs"),
+    ),
+];
+
+const GO: &[(&str, &str)] = &[("go.mod", include_str!("../../../assets/scaffold/go/go.mod"))];
+
+const TYPESCRIPT: &[(&str, &str)] = &[(
+    "tsconfig.json",
+    include_str!("../../../assets/scaffold/typescript/tsconfig.json"),
+)];
+
+const JAVASCRIPT: &[(&str, &str)] = &[(
+    "jsconfig.json",
+    include_str!("../../../assets/scaffold/javascript/jsconfig.json"),
+)];
+
+const CPP: &[(&str, &str)] = &[
+    (
+        "CMakeLists.txt",
+        include_str!("../../../assets/scaffold/cpp/CMakeLists.txt"),
+    ),
+    (
+        "compile_commands.json",
+        include_str!("../../../assets/scaffold/cpp/compile_commands.json"),
+    ),
+];
+
+const C: &[(&str, &str)] = &[(
+    "compile_commands.json",
+    include_str!("../../../assets/scaffold/c/compile_commands.json"),
+)];
+
+const JAVA: &[(&str, &str)] = &[
+    (
+        ".project",
+        include_str!("../../../assets/scaffold/java/.project"),
+    ),
+    (
+        ".classpath",
+        include_str!("../../../assets/scaffold/java/.classpath"),
+    ),
+];
+
+const PYTHON: &[(&str, &str)] = &[(
+    "pyrightconfig.json",
+    include_str!("../../../assets/scaffold/python/pyrightconfig.json"),
+)];
+
+/// Returns the project files leetui ships for a language.
+fn builtin_files(lang: Language) -> Vec<ScaffoldFile> {
+    let files = match lang {
+        Language::Rust => RUST,
+        Language::Go => GO,
+        Language::TypeScript => TYPESCRIPT,
+        Language::JavaScript => JAVASCRIPT,
+        Language::Cpp => CPP,
+        Language::C => C,
+        Language::Java => JAVA,
+        Language::Python | Language::Python3 => PYTHON,
+        _ => &[],
+    };
+
+    files
+        .iter()
+        .map(|(name, contents)| ScaffoldFile {
+            name: name.to_string(),
+            contents: contents.to_string(),
+        })
+        .collect()
+}
+
+/// Checks whether a file is one of the project files of a language, which
+/// are the user's ones if the language's scaffolding is overridden.
+///
+/// # Arguments
+/// * `name` - The name of the file.
+/// * `lang` - The language whose project files are checked.
+pub(super) fn is_scaffold_file(name: &str, lang: Language) -> bool {
+    let dir = override_dir(lang);
+    if dir.is_dir() {
+        return dir.join(name).is_file();
+    }
+
+    builtin_files(lang).iter().any(|file| file.name == name)
+}
+
+/// Returns the directory where the user can override the scaffolding of a language.
+fn override_dir(lang: Language) -> PathBuf {
+    utils::config_dir().join("scaffold").join(lang.ext())
+}
+
+/// Reads the user's scaffolding for a language.
+///
+/// # Returns
+/// The files of the override directory, or `None` if it doesn't exist.
+fn user_files(lang: Language) -> io::Result<Option<Vec<ScaffoldFile>>> {
+    let dir = override_dir(lang);
+    if !dir.is_dir() {
+        return Ok(None);
+    }
+
+    let mut files = Vec::new();
+    for entry in fs::read_dir(dir)?.flatten() {
+        if !entry.file_type()?.is_file() {
+            continue;
+        }
+
+        files.push(ScaffoldFile {
+            name: entry.file_name().to_string_lossy().into_owned(),
+            contents: fs::read_to_string(entry.path())?,
+        });
+    }
+
+    Ok(Some(files))
+}
+
+/// Sets up a minimal project around a solution so language servers can work with it.
+///
+/// The scaffolding of a language can be replaced by placing files under
+/// `<config dir>/scaffold/<ext>/`, where an empty directory disables it.
+/// Files that use `{{solution}}` are rewritten to follow the solution being
+/// worked on, while the rest are never overwritten once they exist. The
+/// solution file itself is left untouched so it can be submitted as is.
+///
+/// # Arguments
+/// * `dir` - The workspace directory of the problem.
+/// * `slug` - The slug identifier for the problem.
+/// * `solution` - The path to the solution file.
+/// * `lang` - The language the solution is written in.
+pub fn write_scaffold(dir: &Path, slug: &str, solution: &Path, lang: Language) -> io::Result<()> {
+    let files = match user_files(lang)? {
+        Some(files) => files,
+        None => builtin_files(lang),
+    };
+
+    if files.is_empty() {
+        return Ok(());
+    }
+
+    // The project files reference the data structure definitions, so they're always needed.
+    support::write_files(dir, lang)?;
+
+    let name = format!("leetui-{slug}");
+    let solution = solution.file_name().unwrap_or_default().to_string_lossy();
+    let dir_str = dir.to_string_lossy();
+
+    for file in files {
+        let path = dir.join(&file.name);
+        let follows_solution = file.contents.contains("{{solution}}");
+        if path.exists() && !follows_solution {
+            continue;
+        }
+
+        let contents = file
+            .contents
+            .replace("{{name}}", &name)
+            .replace("{{slug}}", slug)
+            .replace("{{solution}}", &solution)
+            .replace("{{dir}}", &dir_str);
+
+        // Rewriting an unchanged file would make build tools redo their work.
+        if fs::read_to_string(&path).is_ok_and(|current| current == contents) {
+            continue;
+        }
+
+        fs::write(path, contents)?;
+    }
+
+    Ok(())
+}
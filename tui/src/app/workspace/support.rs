@@ -0,0 +1,117 @@
+use std::{fs, io, path::Path};
+
+use api::{Language, Question};
+
+/// A file written next to the solutions so they compile locally.
+struct SupportFile {
+    name: &'static str,
+    contents: &'static str,
+}
+
+const RUST: &[SupportFile] = &[SupportFile {
+    name: "leetcode.rs",
+    contents: include_str!("../../../assets/support/rust/leetcode.rs"),
+}];
+
+const PYTHON: &[SupportFile] = &[
+    SupportFile {
+        name: "leetcode.py",
+        contents: include_str!("../../../assets/support/python/leetcode.py"),
+    },
+    SupportFile {
+        name: "__builtins__.pyi",
+        contents: include_str!("../../../assets/support/python/__builtins__.pyi"),
+    },
+];
+
+const CPP: &[SupportFile] = &[
+    SupportFile {
+        name: "leetcode.h",
+        contents: include_str!("../../../assets/support/cpp/leetcode.h"),
+    },
+    SupportFile {
+        name: ".clangd",
+        contents: include_str!("../../../assets/support/cpp/.clangd"),
+    },
+];
+
+const JAVA: &[SupportFile] = &[
+    SupportFile {
+        name: "ListNode.java",
+        contents: include_str!("../../../assets/support/java/ListNode.java"),
+    },
+    SupportFile {
+        name: "TreeNode.java",
+        contents: include_str!("../../../assets/support/java/TreeNode.java"),
+    },
+];
+
+const GO: &[SupportFile] = &[SupportFile {
+    name: "leetcode.go",
+    contents: include_str!("../../../assets/support/go/leetcode.go"),
+}];
+
+/// Returns the support files available for a language.
+fn support_files(lang: Language) -> &'static [SupportFile] {
+    match lang {
+        Language::Rust => RUST,
+        Language::Python | Language::Python3 => PYTHON,
+        Language::Cpp => CPP,
+        Language::Java => JAVA,
+        Language::Go => GO,
+        _ => &[],
+    }
+}
+
+/// Checks whether a file is one of the support files of any language.
+pub(super) fn is_support_file(name: &str) -> bool {
+    [RUST, PYTHON, CPP, JAVA, GO]
+        .iter()
+        .flat_map(|files| files.iter())
+        .any(|file| file.name == name)
+}
+
+/// Checks whether the question works with the data structures LeetCode
+/// defines on behalf of the user, such as `ListNode` or `TreeNode`.
+fn uses_data_structures(question: &Question) -> bool {
+    let meta_data = &question.meta_data;
+
+    meta_data
+        .params
+        .iter()
+        .map(|p| p.param_type.as_str())
+        .chain([meta_data.return_type.inner.as_str()])
+        .any(|ty| ty.contains("ListNode") || ty.contains("TreeNode"))
+}
+
+/// Writes the data structure definitions a question relies on into its workspace.
+///
+/// Files that already exist are left untouched so local changes are kept.
+///
+/// # Arguments
+/// * `dir` - The workspace directory of the problem.
+/// * `question` - The question being solved.
+/// * `lang` - The language the solution is written in.
+pub fn write_support_files(dir: &Path, question: &Question, lang: Language) -> io::Result<()> {
+    if !uses_data_structures(question) {
+        return Ok(());
+    }
+
+    write_files(dir, lang)
+}
+
+/// Writes every support file of a language into a workspace, skipping existing ones.
+///
+/// # Arguments
+/// * `dir` - The workspace directory of the problem.
+/// * `lang` - The language the solution is written in.
+pub(super) fn write_files(dir: &Path, lang: Language) -> io::Result<()> {
+    for file in support_files(lang) {
+        let path = dir.join(file.name);
+        if !path.exists() {
+            fs::write(path, file.contents)?;
+        }
+    }
+
+    Ok(())
+}
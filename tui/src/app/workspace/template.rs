@@ -0,0 +1,328 @@
+use std::{
+    fs, io,
+    path::PathBuf,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use api::{Language, Question};
+
+use crate::app::utils;
+
+/// The marker of a line that's only kept locally, e.g. `use std::collections::*; // leetui:local`.
+const LOCAL_LINE: &str = "leetui:local";
+
+/// The marker naming the language of a solution whose extension is shared
+/// with another language, e.g. `# leetui:lang=Python leetui:local`.
+const LANG_MARKER: &str = "leetui:lang=";
+
+/// The languages that share their extension with another one.
+const SHARED_EXTENSION: [Language; 2] = [Language::Python, Language::Python3];
+
+/// The width description comments are wrapped at, not counting the comment prefix.
+const COMMENT_WIDTH: usize = 80;
+
+/// The markers around a region that's only kept locally, on lines of their own.
+const LOCAL_START: &str = "leetui:local-start";
+const LOCAL_END: &str = "leetui:local-end";
+
+/// Returns the path of the user's template for a language.
+fn template_path(lang: Language) -> PathBuf {
+    utils::config_dir()
+        .join("templates")
+        .join(format!("template.{}", lang.ext()))
+}
+
+/// Returns the prefix of a line comment in a language.
+fn line_comment(lang: Language) -> &'static str {
+    match lang {
+        Language::Python | Language::Python3 | Language::Ruby | Language::Elixir => "#",
+        Language::Erlang => "%",
+        Language::Racket => ";;",
+        _ => "//",
+    }
+}
+
+/// Returns the imports LeetCode's judge provides implicitly for a language,
+/// so solutions also compile locally.
+fn default_imports(lang: Language) -> &'static str {
+    match lang {
+        Language::Rust => "use std::collections::*;",
+        Language::Python | Language::Python3 => {
+            "from typing import *\nfrom collections import *\nimport heapq\nimport bisect\nimport math"
+        }
+        Language::Java => "import java.util.*;",
+        Language::Cpp => "#include <bits/stdc++.h>\nusing namespace std;",
+        Language::Csharp => "using System;\nusing System.Collections.Generic;\nusing System.Linq;",
+        Language::Swift => "import Foundation",
+        _ => "",
+    }
+}
+
+/// Creates the contents of a new solution, expanding the user's template for
+/// the language if there is one.
+///
+/// Templates may use the `{{snippet}}`, `{{title}}`, `{{slug}}`, `{{url}}`,
+/// `{{difficulty}}`, `{{date}}`, `{{description_comment}}` and `{{imports}}`
+/// placeholders. Lines ending with `leetui:local`, and regions between
+/// `leetui:local-start` and `leetui:local-end` lines, are removed before the
+/// solution is sent to LeetCode.
+///
+/// # Arguments
+/// * `question` - The question being solved.
+/// * `lang` - The language of the solution.
+/// * `snippet` - The code LeetCode starts the solution with.
+///
+/// # Returns
+/// The contents of the solution, which is the snippet alone without a template.
+pub fn render_solution(question: &Question, lang: Language, snippet: &str) -> io::Result<String> {
+    let template = match fs::read_to_string(template_path(lang)) {
+        Ok(template) => template,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(snippet.to_string()),
+        Err(e) => return Err(e),
+    };
+
+    let description_comment = description_comment(question, lang);
+    let url = problem_url(&question.title_slug);
+
+    Ok(template
+        .replace("{{title}}", &question.title)
+        .replace("{{slug}}", &question.title_slug)
+        .replace("{{url}}", &url)
+        .replace("{{difficulty}}", &format!("{:?}", question.difficulty))
+        .replace("{{date}}", &today())
+        .replace("{{imports}}", default_imports(lang))
+        .replace("{{description_comment}}", &description_comment)
+        // The snippet goes last so placeholders in its code are left alone.
+        .replace("{{snippet}}", snippet))
+}
+
+/// Returns the description of a question as line comments of a language,
+/// wrapped so it reads well in an editor.
+fn description_comment(question: &Question, lang: Language) -> String {
+    let comment = line_comment(lang);
+    let mut lines = Vec::new();
+
+    for line in question.document().to_plain().lines() {
+        if line.trim().is_empty() {
+            lines.push(comment.to_string());
+            continue;
+        }
+
+        let indent = &line[..line.len() - line.trim_start().len()];
+        let mut current = String::new();
+
+        for word in line.split_whitespace() {
+            if !current.is_empty() && indent.len() + current.len() + word.len() + 1 > COMMENT_WIDTH
+            {
+                lines.push(format!("{comment} {indent}{current}"));
+                current.clear();
+            }
+
+            if !current.is_empty() {
+                current.push(' ');
+            }
+            current.push_str(word);
+        }
+
+        lines.push(format!("{comment} {indent}{current}"));
+    }
+
+    lines.join("\n")
+}
+
+/// Returns the url of a problem's page.
+///
+/// # Arguments
+/// * `slug` - The slug of the problem.
+pub fn problem_url(slug: &str) -> String {
+    format!("https://leetcode.com/problems/{slug}/")
+}
+
+/// Puts the description of a question on top of a new solution as a
+/// local-only comment, so it's in sight while coding but never submitted.
+///
+/// # Arguments
+/// * `code` - The contents of the new solution.
+/// * `question` - The question being solved.
+/// * `lang` - The language of the solution.
+pub fn with_description_header(code: &str, question: &Question, lang: Language) -> String {
+    let comment = line_comment(lang);
+    let title = format!("{comment} {}. {}", question.question_id, question.title);
+    let url = format!("{comment} {}", problem_url(&question.title_slug));
+
+    format!(
+        "{comment} {LOCAL_START}\n{title}\n{url}\n{comment}\n{}\n{comment} {LOCAL_END}\n\n{code}",
+        description_comment(question, lang),
+    )
+}
+
+/// Puts a local-only marker naming the language on top of a new solution if
+/// its extension could be mistaken for another language's.
+///
+/// # Arguments
+/// * `code` - The contents of the new solution.
+/// * `lang` - The language of the solution.
+pub fn with_language_marker(code: &str, lang: Language) -> String {
+    if !SHARED_EXTENSION.contains(&lang) {
+        return code.to_string();
+    }
+
+    format!(
+        "{} {LANG_MARKER}{lang} {LOCAL_LINE}\n{code}",
+        line_comment(lang)
+    )
+}
+
+/// Puts a local-only `package main` clause on top of a new Go solution,
+/// since LeetCode's snippets leave it out but the solution has to compile
+/// together with the project files next to it.
+///
+/// # Arguments
+/// * `code` - The contents of the new solution.
+/// * `lang` - The language of the solution.
+pub fn with_package_clause(code: &str, lang: Language) -> String {
+    let declared = code
+        .lines()
+        .any(|line| line.trim_start().starts_with("package "));
+
+    if lang != Language::Go || declared {
+        return code.to_string();
+    }
+
+    format!("package main // {LOCAL_LINE}\n\n{code}")
+}
+
+/// Reads the language marker of a solution, which is on one of its first lines.
+///
+/// # Arguments
+/// * `code` - The code of the solution.
+pub(super) fn marked_language(code: &str) -> Option<Language> {
+    let name = code
+        .lines()
+        .take(5)
+        .find_map(|line| line.split_once(LANG_MARKER))?
+        .1
+        .split_whitespace()
+        .next()?;
+
+    SHARED_EXTENSION
+        .into_iter()
+        .find(|lang| lang.to_string() == name)
+}
+
+/// Removes the local-only lines and regions of a solution.
+///
+/// # Arguments
+/// * `code` - The code of the solution.
+///
+/// # Returns
+/// The code to send to LeetCode.
+pub fn strip_local_only(code: &str) -> String {
+    let mut stripped = Vec::new();
+    let mut in_region = false;
+
+    for line in code.lines() {
+        let trimmed = line.trim_end();
+
+        if trimmed.ends_with(LOCAL_START) {
+            in_region = true;
+        } else if trimmed.ends_with(LOCAL_END) {
+            in_region = false;
+        } else if !in_region && !trimmed.ends_with(LOCAL_LINE) {
+            stripped.push(line);
+        }
+    }
+
+    let mut stripped = stripped.join("\n");
+    if code.ends_with('\n') {
+        stripped.push('\n');
+    }
+
+    stripped
+}
+
+/// Returns today's date in UTC, formatted as `YYYY-MM-DD`.
+fn today() -> String {
+    let secs = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or_default();
+
+    // Howard Hinnant's algorithm to turn days since the epoch into a civil date.
+    let days = (secs / 86_400) as i64 + 719_468;
+    let era = days.div_euclid(146_097);
+    let doe = days.rem_euclid(146_097);
+    let yoe = (doe - doe / 1_460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + i64::from(month <= 2);
+
+    format!("{year:04}-{month:02}-{day:02}")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn declares_a_package_for_go() {
+        let snippet = "func twoSum(nums []int, target int) []int {\n\n}";
+        let code = with_package_clause(snippet, Language::Go);
+
+        assert_eq!(code.lines().next(), Some("package main // leetui:local"));
+        assert_eq!(strip_local_only(&code).trim_start(), snippet);
+    }
+
+    #[test]
+    fn keeps_existing_package_clauses() {
+        let code = "package main\n\nfunc f() {}";
+        assert_eq!(with_package_clause(code, Language::Go), code);
+        assert_eq!(
+            with_package_clause("fn f() {}", Language::Rust),
+            "fn f() {}"
+        );
+    }
+
+    #[test]
+    fn strips_local_lines() {
+        let code = "import os  # leetui:local\nclass Solution:\n    pass\n";
+        assert_eq!(strip_local_only(code), "class Solution:\n    pass\n");
+    }
+
+    #[test]
+    fn strips_local_regions_with_their_markers() {
+        let code = "fn f() {}\n// leetui:local-start\nfn main() {\n    f();\n}\n// leetui:local-end\nfn g() {}";
+        assert_eq!(strip_local_only(code), "fn f() {}\nfn g() {}");
+    }
+
+    #[test]
+    fn keeps_the_trailing_newline_as_is() {
+        assert_eq!(strip_local_only("a\nb // leetui:local\n"), "a\n");
+        assert_eq!(strip_local_only("a\nb // leetui:local"), "a");
+    }
+
+    #[test]
+    fn keeps_markers_in_the_middle_of_a_line() {
+        let code = "let x = 1; // leetui:local here\nlet s = \"// leetui:local-start\";";
+        assert_eq!(strip_local_only(code), code);
+    }
+
+    #[test]
+    fn reads_the_language_marker() {
+        for lang in SHARED_EXTENSION {
+            let code = with_language_marker("class Solution:\n    pass", lang);
+            assert_eq!(marked_language(&code), Some(lang));
+            assert!(!strip_local_only(&code).contains(LANG_MARKER));
+        }
+    }
+
+    #[test]
+    fn ignores_late_or_unknown_language_markers() {
+        let late = format!("{}# leetui:lang=Python # leetui:local", "\n".repeat(5));
+        assert_eq!(marked_language(&late), None);
+        assert_eq!(marked_language("# leetui:lang=Cobol # leetui:local"), None);
+        assert_eq!(marked_language("class Solution:"), None);
+    }
+}
@@ -0,0 +1,132 @@
+use std::{fs, io, path::Path};
+
+use crate::app::app::TestCase;
+
+/// The file holding the inputs, in LeetCode's newline-separated format.
+const INPUTS_FILE: &str = "testcases.txt";
+
+/// The file holding the expected outputs, one line per test case.
+const EXPECTED_FILE: &str = "expected.txt";
+
+/// Loads the test cases saved in a problem's workspace.
+///
+/// # Arguments
+/// * `dir` - The workspace directory of the problem.
+/// * `param_count` - The amount of inputs each test case has.
+///
+/// # Returns
+/// The saved test cases, or `None` if there are none, and an error if
+/// their inputs don't match the problem's parameters.
+pub fn load_test_cases(dir: &Path, param_count: usize) -> io::Result<Option<Vec<TestCase>>> {
+    let inputs_path = dir.join(INPUTS_FILE);
+    if !inputs_path.exists() || param_count == 0 {
+        return Ok(None);
+    }
+
+    let inputs = fs::read_to_string(inputs_path)?;
+    let lines = file_lines(&inputs);
+
+    if !lines.len().is_multiple_of(param_count) {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!(
+                "{INPUTS_FILE} has {} lines, which doesn't fit cases of {param_count} inputs",
+                lines.len()
+            ),
+        ));
+    }
+
+    let expected = fs::read_to_string(dir.join(EXPECTED_FILE)).unwrap_or_default();
+    let mut expected = file_lines(&expected).into_iter();
+
+    let cases = lines
+        .chunks(param_count)
+        .map(|chunk| TestCase {
+            input: chunk.iter().map(|s| s.to_string()).collect(),
+            output: None,
+            expected: expected.next().filter(|e| !e.is_empty()).map(String::from),
+        })
+        .collect();
+
+    Ok(Some(cases))
+}
+
+/// Saves the test cases into a problem's workspace.
+///
+/// # Arguments
+/// * `dir` - The workspace directory of the problem.
+/// * `cases` - The test cases to save.
+pub fn save_test_cases(dir: &Path, cases: &[TestCase]) -> io::Result<()> {
+    let inputs: Vec<_> = cases.iter().flat_map(|c| &c.input).cloned().collect();
+    let expected: Vec<_> = cases
+        .iter()
+        .map(|c| c.expected.clone().unwrap_or_default())
+        .collect();
+
+    fs::write(dir.join(INPUTS_FILE), with_final_newline(&inputs))?;
+    fs::write(dir.join(EXPECTED_FILE), with_final_newline(&expected))
+}
+
+/// Splits a saved file into its lines.
+///
+/// Inputs may be left empty, so every line counts, except after the final
+/// newline that's written on save and that editors add.
+fn file_lines(contents: &str) -> Vec<&str> {
+    if contents.is_empty() {
+        return Vec::new();
+    }
+
+    let contents = contents.strip_suffix('\n').unwrap_or(contents);
+    contents
+        .split('\n')
+        .map(|line| line.strip_suffix('\r').unwrap_or(line))
+        .collect()
+}
+
+/// Joins lines into the contents of a file, ending with a newline.
+fn with_final_newline(lines: &[String]) -> String {
+    match lines.is_empty() {
+        true => String::new(),
+        false => format!("{}\n", lines.join("\n")),
+    }
+}
+
+/// Removes the test cases saved in a problem's workspace.
+///
+/// # Arguments
+/// * `dir` - The workspace directory of the problem.
+pub fn remove_test_cases(dir: &Path) -> io::Result<()> {
+    for file in [INPUTS_FILE, EXPECTED_FILE] {
+        match fs::remove_file(dir.join(file)) {
+            Err(e) if e.kind() != io::ErrorKind::NotFound => return Err(e),
+            _ => {}
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ignores_the_final_newline() {
+        assert_eq!(file_lines("[1,2]\n3\n"), ["[1,2]", "3"]);
+        assert_eq!(file_lines("[1,2]\r\n3\r\n"), ["[1,2]", "3"]);
+        assert_eq!(file_lines("[1,2]\n3"), ["[1,2]", "3"]);
+    }
+
+    #[test]
+    fn keeps_empty_inputs() {
+        assert_eq!(file_lines("a\n\n"), ["a", ""]);
+        assert_eq!(file_lines("\n"), [""]);
+        assert!(file_lines("").is_empty());
+    }
+
+    #[test]
+    fn round_trips_empty_last_inputs() {
+        let lines = vec!["a".to_string(), String::new()];
+        assert_eq!(file_lines(&with_final_newline(&lines)), lines);
+    }
+}
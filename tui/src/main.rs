@@ -1,68 +1,216 @@
 mod app;
 
-use std::{env, error::Error, time::Duration};
+use std::{env, error::Error, io, panic, path::PathBuf, process, time::Duration};
 
 use api::LeetCodeClient;
-use ratatui::DefaultTerminal;
-use tokio::{sync::mpsc, time};
+use futures::StreamExt;
+use ratatui::{
+    DefaultTerminal,
+    crossterm::{
+        event::{DisableMouseCapture, EnableMouseCapture, EventStream},
+        execute,
+        terminal::{self, EnterAlternateScreen},
+    },
+};
+use tokio::{
+    sync::mpsc,
+    time::{self, Interval, MissedTickBehavior},
+};
 
-use app::{App, UpdateResult, handler};
+use app::{Action, App, UpdateResult, config::Config, handler, images::Images};
 
-/// Retrieves the needed LeetCode variables to create the `LeetCodeClient` api.
+const USAGE: &str = "usage: leetui [--config <path>]";
+
+/// The time in between frames of the loading spinner.
+const SPINNER_INTERVAL: Duration = Duration::from_millis(80);
+
+/// The time in between checks of an editor running next to leetui.
+const POLL_INTERVAL: Duration = Duration::from_millis(250);
+
+/// Reads the command line arguments.
 ///
 /// # Returns
-/// The variables or an error if the variables are undefined.
-fn retrieve_leetcode_vars() -> Result<(String, String), &'static str> {
-    let session = env::var("LEETCODE_SESSION").map_err(|_| "LEETCODE_SESSION is not defined")?;
-    let csrf = env::var("CSRF_TOKEN").map_err(|_| "CSRF_TOKEN is not defined")?;
-    Ok((session, csrf))
+/// The configuration file given with `--config`, if any, or an error for
+/// arguments that aren't known.
+fn parse_args() -> Result<Option<PathBuf>, String> {
+    let mut args = env::args().skip(1);
+    let mut config = None;
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--config" | "-c" => {
+                let path = args.next().ok_or("--config expects a path")?;
+                config = Some(PathBuf::from(path));
+            }
+            "--help" | "-h" => {
+                println!("{USAGE}");
+                process::exit(0);
+            }
+            _ => match arg.strip_prefix("--config=") {
+                Some(path) => config = Some(PathBuf::from(path)),
+                None => return Err(format!("unknown argument {arg:?}\n{USAGE}")),
+            },
+        }
+    }
+
+    Ok(config)
+}
+
+/// Loads the configuration and credentials, before the terminal is taken
+/// over so that mistakes are printed plainly.
+fn setup() -> Result<(Config, String, String), Box<dyn Error>> {
+    let config_path = parse_args()?;
+    let config = Config::load(config_path.as_deref())?;
+    let (session, csrf) = config.credentials.resolve()?;
+    Ok((config, session, csrf))
+}
+
+/// Takes over the terminal, reporting the mouse too if enabled. Since it
+/// installs the panic hooks, it must only run once.
+///
+/// # Arguments
+/// * `mouse` - Whether to capture the mouse.
+fn init_terminal(mouse: bool) -> DefaultTerminal {
+    let terminal = ratatui::init();
+    if mouse {
+        let _ = execute!(io::stdout(), EnableMouseCapture);
+
+        // ratatui's own hook restores the terminal but leaves the mouse captured.
+        let hook = panic::take_hook();
+        panic::set_hook(Box::new(move |info| {
+            let _ = execute!(io::stdout(), DisableMouseCapture);
+            hook(info);
+        }));
+    }
+
+    terminal
+}
+
+/// Takes the terminal back after `restore_terminal`, without installing the
+/// panic hooks again.
+///
+/// # Arguments
+/// * `mouse` - Whether to capture the mouse.
+fn resume_terminal(mouse: bool) -> io::Result<()> {
+    terminal::enable_raw_mode()?;
+    execute!(io::stdout(), EnterAlternateScreen)?;
+    if mouse {
+        execute!(io::stdout(), EnableMouseCapture)?;
+    }
+
+    Ok(())
+}
+
+/// Gives the terminal back, letting go of the mouse first.
+///
+/// # Arguments
+/// * `mouse` - Whether the mouse was captured.
+fn restore_terminal(mouse: bool) {
+    if mouse {
+        let _ = execute!(io::stdout(), DisableMouseCapture);
+    }
+
+    ratatui::restore();
 }
 
 /// Setups and runs the entire application.
 ///
 /// # Arguments
 /// * `terminal` - The terminal instance to use.
+/// * `config` - The user's settings.
+/// * `session` - The LeetCode session cookie.
+/// * `csrf` - The LeetCode csrf token.
 ///
 /// # Returns
 /// Any generic error the application has.
-async fn run_app(terminal: &mut DefaultTerminal) -> Result<(), Box<dyn Error>> {
+async fn run_app(
+    terminal: &mut DefaultTerminal,
+    config: Config,
+    session: String,
+    csrf: String,
+) -> Result<(), Box<dyn Error>> {
     let (client_tx, client_rx) = mpsc::channel(100);
     let (action_tx, mut action_rx) = mpsc::channel(100);
-    let throbber_interval = time::interval(Duration::from_millis(30));
 
-    let (session, csrf) = retrieve_leetcode_vars()?;
-    let client = LeetCodeClient::new(session.clone(), csrf.clone())?;
-    let mut app = App::new(client_tx);
+    let client = LeetCodeClient::new(session, csrf)?;
+
+    // The terminal has to be queried before the event stream starts reading its input.
+    let picker = config.features.images.then(Images::query_picker).flatten();
+    let images = Images::new(picker);
+    let mouse = config.features.mouse;
+    let mut app = App::new(client_tx, images, config);
 
-    // Initialize the input listeners.
-    tokio::spawn(handler::spawn_keyboard(action_tx.clone()));
-    tokio::spawn(handler::spawn_ticker(action_tx.clone(), throbber_interval));
     tokio::spawn(handler::spawn_client(action_tx, client_rx, client));
 
-    while let Some(action) = action_rx.recv().await {
+    let mut events = EventStream::new();
+    let mut spinner = ticker(SPINNER_INTERVAL);
+    let mut poll = ticker(POLL_INTERVAL);
+    let mut dirty = true;
+
+    loop {
+        if dirty {
+            terminal.draw(|f| app.render(f))?;
+            dirty = false;
+        }
+
+        // The timers only run while there's something to animate or check,
+        // so an idle leetui sleeps until the next event.
+        let action = tokio::select! {
+            Some(event) = events.next() => match handler::event_action(event?) {
+                Some(action) => action,
+                None => continue,
+            },
+            Some(action) = action_rx.recv() => action,
+            _ = spinner.tick(), if app.is_loading => Action::Tick,
+            _ = poll.tick(), if app.is_watching() => Action::Poll,
+            else => break,
+        };
+
         match app.update(action) {
-            UpdateResult::Continue => {
-                terminal.draw(|f| app.render(f))?;
-            }
+            UpdateResult::Continue => dirty = true,
             UpdateResult::OpenEditor => {
-                ratatui::restore();
+                // The stream would otherwise keep reading the input meant for the editor.
+                drop(events);
+                restore_terminal(mouse);
                 app.open_editor()?;
-                *terminal = ratatui::init();
+                resume_terminal(mouse)?;
                 terminal.clear()?;
-                terminal.draw(|f| app.render(f))?;
+                events = EventStream::new();
+                dirty = true;
             }
             UpdateResult::SkipRendering => {}
             UpdateResult::Exit => break,
         }
     }
 
+    app.finish_hung_up_editors();
     Ok(())
 }
 
+/// Creates an interval that waits a whole period after being resumed,
+/// instead of catching up on the ticks it missed while disabled.
+///
+/// # Arguments
+/// * `period` - The time in between ticks.
+fn ticker(period: Duration) -> Interval {
+    let mut interval = time::interval(period);
+    interval.set_missed_tick_behavior(MissedTickBehavior::Delay);
+    interval
+}
+
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn Error>> {
-    let mut terminal = ratatui::init();
-    let res = run_app(&mut terminal).await;
-    ratatui::restore();
+    let (config, session, csrf) = match setup() {
+        Ok(setup) => setup,
+        Err(e) => {
+            eprintln!("leetui: {e}");
+            process::exit(1);
+        }
+    };
+
+    let mouse = config.features.mouse;
+    let mut terminal = init_terminal(mouse);
+    let res = run_app(&mut terminal, config, session, csrf).await;
+    restore_terminal(mouse);
     res
 }